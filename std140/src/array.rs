@@ -1,16 +1,92 @@
 use ::std::{
     fmt,
+    iter::FusedIterator,
     ops::{Deref, DerefMut},
+    slice,
 };
 
-use crate::{ReprStd140, Std140ArrayElement};
+use crate::{
+    glsl::{GlslType, TypeName},
+    layout::MemberKind,
+    unbounded_array::unbounded_array,
+    ByteOrder, ReprStd140, Std140ArrayElement,
+};
 
+/// Wraps an element of an std140 [array][struct@array] or [unbounded_array], aligning it to 16
+/// bytes.
+///
+/// Prefer constructing it with [AlignmentedElement::new] or [From]; the tuple field remains public
+/// for backwards compatibility.
 #[derive(Clone, Copy, PartialEq, Hash)]
 #[repr(C, align(16))]
 pub struct AlignmentedElement<T>(pub T)
 where
     T: Std140ArrayElement;
 
+impl<T> AlignmentedElement<T>
+where
+    T: Std140ArrayElement,
+{
+    /// Checked whenever an element is created, see [AlignmentedElement::new].
+    const STD140_STRIDE: () = assert!(
+        ::std::mem::size_of::<Self>() % 16 == 0 && ::std::mem::align_of::<Self>() == 16,
+        "std140 array elements must have a 16 byte aligned stride"
+    );
+
+    /// Wraps `value` as an array element.
+    ///
+    /// # Over-aligned elements
+    ///
+    /// Every std140 array element occupies a multiple of 16 bytes and is aligned to exactly 16
+    /// bytes. An element type implementing [Std140ArrayElement] with an alignment above 16 bytes
+    /// would break that stride, so wrapping it is rejected at compile time, just like creating an
+    /// [array][struct@array] through [array::new][crate::array::array::new].
+    ///
+    /// ```compile_fail
+    /// #[repr(C, align(32))]
+    /// #[derive(Clone, Copy)]
+    /// struct Wide([f32; 8]);
+    ///
+    /// impl std140::glsl::GlslType for Wide {
+    ///     const GLSL_NAME: &'static str = "Wide";
+    /// }
+    ///
+    /// unsafe impl std140::ReprStd140 for Wide {
+    ///     const STD140_KIND: std140::layout::MemberKind = std140::layout::MemberKind::Scalar;
+    ///     const STD140_PADDING: usize = 0;
+    /// }
+    /// unsafe impl std140::Std140ArrayElement for Wide {}
+    ///
+    /// let element = std140::array::AlignmentedElement::new(Wide([0.0; 8]));
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::{array::AlignmentedElement, uint};
+    ///
+    /// let element = AlignmentedElement::new(uint(3));
+    ///
+    /// assert_eq!(*element, uint(3));
+    /// ```
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        let () = Self::STD140_STRIDE;
+
+        Self(value)
+    }
+}
+
+impl<T> From<T> for AlignmentedElement<T>
+where
+    T: Std140ArrayElement,
+{
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
 impl<T> fmt::Debug for AlignmentedElement<T>
 where
     T: Std140ArrayElement + fmt::Debug,
@@ -43,7 +119,28 @@ where
 /// Represents an std140 compatible array.
 ///
 /// All elements in an std140 array are aligned to at least 16 bytes.
+///
+/// Arrays may themselves be used as array elements to represent GLSL arrays of arrays, e.g. the
+/// GLSL member `uint table[2][3]` is compatible with a field of type `array<array<uint, 3>, 2>`.
+///
+/// # Slices
+///
+/// An array dereferences to a slice of [AlignmentedElement]s, so slice methods such as `split_at`
+/// or `chunks` are available and yield `&[AlignmentedElement<T>]`. Keeping the wrapper preserves
+/// the 16 byte stride of the elements, and each element dereferences to the wrapped `T`.
+///
+/// ```
+/// use std140::{array::array, uint};
+///
+/// let ids: array<uint, 8> = array::from_fn(|i| uint(i as u32));
+/// let (front, back) = ids.split_at(4);
+///
+/// assert_eq!(front.len(), 4);
+/// assert_eq!(*back[0], uint(4));
+/// assert!(back.iter().map(|id| id.0).eq((4..8).map(uint)));
+/// ```
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct array<T, const LEN: usize>([AlignmentedElement<T>; LEN])
 where
     T: Std140ArrayElement;
@@ -52,10 +149,246 @@ impl<T, const LEN: usize> array<T, { LEN }>
 where
     T: Std140ArrayElement,
 {
+    /// Checked whenever an array is created, see [array::new].
+    const NONZERO_LEN: () = assert!(LEN > 0, "std140 arrays must have at least one element");
+
+    /// Creates an array from its wrapped elements.
+    ///
+    /// # Zero-length arrays
+    ///
+    /// GLSL does not allow arrays without elements, so creating an `array<T, 0>` is rejected at
+    /// compile time. The check is a constant evaluated when this function (or any other function
+    /// creating an array, such as [Self::from_fn] or the [array!][crate::array!] macro) is
+    /// instantiated for a zero length, rather than a bound on the type itself, so it is reported
+    /// as a constant evaluation error. Using a zero-length array type as a field of a struct marked
+    /// with [`#[repr_std140]`][crate::repr_std140] is rejected in the same way.
+    ///
+    /// ```compile_fail
+    /// let empty: std140::array::array<std140::uint, 0> = std140::array::array::new([]);
+    /// ```
+    ///
+    /// Element types with an alignment above 16 bytes are rejected in the same way, see
+    /// [AlignmentedElement::new].
     #[inline]
     pub const fn new(inner: [AlignmentedElement<T>; LEN]) -> Self {
+        let () = Self::NONZERO_LEN;
+        let () = AlignmentedElement::<T>::STD140_STRIDE;
+
         Self(inner)
     }
+
+    /// Returns the wrapped elements, e.g. to access them in a `const` context.
+    pub(crate) const fn elements(&self) -> &[AlignmentedElement<T>; LEN] {
+        &self.0
+    }
+
+    /// Creates an array where each element is the result of calling `f` with the element's index.
+    ///
+    /// Unlike the [`array![value; LEN]`][crate::array!] form of the array macro, this does not
+    /// require the element type to implement [Copy].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::{array::array, uint};
+    ///
+    /// fn indices<const N: usize>() -> array<uint, N> {
+    ///     array::from_fn(|i| uint(i as u32))
+    /// }
+    ///
+    /// let values = indices::<3>();
+    ///
+    /// assert_eq!(values, std140::array![uint(0), uint(1), uint(2)]);
+    /// ```
+    ///
+    /// # Building an array of structs from parallel slices
+    ///
+    /// Data kept as a struct of arrays on the CPU side can be converted into the array of structs
+    /// layout a uniform block expects by indexing into each slice:
+    ///
+    /// ```
+    /// use std140::{array::array, float, vec::vec3};
+    ///
+    /// #[std140::repr_std140]
+    /// #[derive(Clone, Copy)]
+    /// struct PointLight {
+    ///     position: vec3,
+    ///     intensity: float,
+    /// }
+    ///
+    /// let positions = [vec3(0.0, 1.0, 0.0), vec3(2.0, 1.0, 0.0)];
+    /// let intensities = [float(0.5), float(1.0)];
+    ///
+    /// let lights: array<PointLight, 2> = array::from_fn(|i| PointLight {
+    ///     position: positions[i],
+    ///     intensity: intensities[i],
+    /// });
+    ///
+    /// assert_eq!(lights[1].0.position, vec3(2.0, 1.0, 0.0));
+    /// assert_eq!(lights[1].0.intensity, float(1.0));
+    /// ```
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        Self::new(::std::array::from_fn(|i| AlignmentedElement(f(i))))
+    }
+
+    /// Creates an array with a clone of `value` in every slot.
+    ///
+    /// Unlike the [`array![value; LEN]`][crate::array!] form of the array macro, this only requires
+    /// the element type to implement [Clone], not [Copy].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::array::array;
+    ///
+    /// #[std140::repr_std140]
+    /// #[derive(Clone, PartialEq, Debug)]
+    /// struct Material {
+    ///     color: std140::vec::vec4,
+    /// }
+    ///
+    /// let materials: array<Material, 3> = array::filled(Material {
+    ///     color: std140::vec::vec4(1.0, 1.0, 1.0, 1.0),
+    /// });
+    ///
+    /// assert_eq!(materials[2].color, std140::vec::vec4(1.0, 1.0, 1.0, 1.0));
+    /// ```
+    pub fn filled(value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(|_| value.clone())
+    }
+
+    /// Creates an array with a copy of `value` in every slot.
+    ///
+    /// This is equivalent to [`array![value; LEN]`][crate::array!], but can be called as a
+    /// function, including in constant contexts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::{array::array, uint};
+    ///
+    /// const DEFAULTS: array<uint, 4> = array::repeat(uint(0));
+    ///
+    /// assert_eq!(DEFAULTS[3].0, uint(0));
+    /// ```
+    #[inline]
+    pub const fn repeat(value: T) -> Self
+    where
+        T: Copy,
+    {
+        Self::new([AlignmentedElement::new(value); LEN])
+    }
+
+    /// Returns the first element of the array, or `None` if it is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[std140::repr_std140]
+    /// struct PointLight {
+    ///     position: std140::vec::vec3,
+    ///     intensity: std140::float,
+    /// }
+    ///
+    /// let lights: std140::array::array<PointLight, 2> = std140::array![
+    ///     PointLight {
+    ///         position: std140::vec::vec3(10.0, 0.0, 10.0),
+    ///         intensity: std140::float(0.5)
+    ///     },
+    ///     PointLight {
+    ///         position: std140::vec::vec3(0.0, 10.0, 10.0),
+    ///         intensity: std140::float(0.8)
+    ///     },
+    /// ];
+    ///
+    /// let first: Option<&PointLight> = lights.first();
+    ///
+    /// assert_eq!(first.map(|light| light.intensity), Some(std140::float(0.5)));
+    /// ```
+    #[inline]
+    pub fn first(&self) -> Option<&T> {
+        self.0.first().map(|element| &element.0)
+    }
+
+    /// Returns the last element of the array, or `None` if it is empty.
+    #[inline]
+    pub fn last(&self) -> Option<&T> {
+        self.0.last().map(|element| &element.0)
+    }
+
+    /// Returns `true` if the array contains an element equal to `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::uint;
+    ///
+    /// let material_ids = std140::array![uint(2), uint(5), uint(7)];
+    ///
+    /// assert!(material_ids.contains(&uint(5)));
+    /// assert!(!material_ids.contains(&uint(3)));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.0.iter().any(|element| element.0 == *value)
+    }
+
+    /// Returns the index of the first element for which `f` returns `true`, or `None` if there is
+    /// no such element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::uint;
+    ///
+    /// let material_ids = std140::array![uint(2), uint(5), uint(7)];
+    ///
+    /// assert_eq!(material_ids.position(|id| *id == uint(5)), Some(1));
+    /// assert_eq!(material_ids.position(|id| *id == uint(3)), None);
+    /// ```
+    pub fn position<F>(&self, mut f: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.0.iter().position(|element| f(&element.0))
+    }
+}
+
+impl<T, const COLUMNS: usize, const ROWS: usize> array<array<T, COLUMNS>, ROWS>
+where
+    T: Std140ArrayElement + Copy,
+{
+    /// Concatenates the inner arrays into a single [unbounded_array], in row order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::{array::array, uint};
+    ///
+    /// let table: array<array<uint, 2>, 2> = std140::array![
+    ///     std140::array![uint(0), uint(1)],
+    ///     std140::array![uint(2), uint(3)],
+    /// ];
+    ///
+    /// let flat = table.flatten_to_vec();
+    ///
+    /// assert_eq!(flat.len(), 4);
+    /// assert_eq!(flat[2].0, uint(2));
+    /// ```
+    pub fn flatten_to_vec(&self) -> unbounded_array<T> {
+        self.0
+            .iter()
+            .flat_map(|row| row.0.iter().copied())
+            .collect()
+    }
 }
 
 impl<T, const LEN: usize> From<[AlignmentedElement<T>; LEN]> for array<T, { LEN }>
@@ -64,7 +397,65 @@ where
 {
     #[inline]
     fn from(inner: [AlignmentedElement<T>; LEN]) -> Self {
-        Self(inner)
+        Self::new(inner)
+    }
+}
+
+impl<T, const LEN: usize> TryFrom<Vec<T>> for array<T, { LEN }>
+where
+    T: Std140ArrayElement,
+{
+    type Error = Vec<T>;
+
+    /// Moves the elements of `vec` into a new array.
+    ///
+    /// Returns the original [Vec] as the error if its length is not `LEN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::{array::array, uint};
+    ///
+    /// let values: array<uint, 2> = vec![uint(0), uint(1)].try_into().unwrap();
+    /// assert_eq!(values, std140::array![uint(0), uint(1)]);
+    ///
+    /// let error = array::<uint, 3>::try_from(vec![uint(0), uint(1)]).unwrap_err();
+    /// assert_eq!(error.len(), 2);
+    /// ```
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        if vec.len() != LEN {
+            return Err(vec);
+        }
+
+        let elements: Vec<AlignmentedElement<T>> =
+            vec.into_iter().map(AlignmentedElement).collect();
+
+        match elements.try_into() {
+            Ok(inner) => Ok(Self::new(inner)),
+            Err(_) => unreachable!("length was checked above"),
+        }
+    }
+}
+
+impl<T, const LEN: usize> From<array<T, { LEN }>> for unbounded_array<T>
+where
+    T: Std140ArrayElement,
+{
+    /// Moves the elements of a fixed size array into a new [unbounded_array], which can then grow
+    /// at runtime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::{array::AlignmentedElement, uint, unbounded_array::unbounded_array};
+    ///
+    /// let mut values = unbounded_array::from(std140::array![uint(0), uint(1)]);
+    /// values.push(AlignmentedElement::new(uint(2)));
+    ///
+    /// assert_eq!(values.last(), Some(&uint(2)));
+    /// ```
+    fn from(fixed: array<T, { LEN }>) -> Self {
+        unbounded_array::new(Vec::from(fixed.0))
     }
 }
 
@@ -119,4 +510,184 @@ where
     }
 }
 
-unsafe impl<T, const LEN: usize> ReprStd140 for array<T, { LEN }> where T: Std140ArrayElement {}
+impl<T, const LEN: usize> IntoIterator for array<T, { LEN }>
+where
+    T: Std140ArrayElement,
+{
+    type Item = T;
+    type IntoIter = IntoIter<T, LEN>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.0.into_iter())
+    }
+}
+
+impl<'a, T, const LEN: usize> IntoIterator for &'a array<T, { LEN }>
+where
+    T: Std140ArrayElement,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter(self.0.iter())
+    }
+}
+
+impl<'a, T, const LEN: usize> IntoIterator for &'a mut array<T, { LEN }>
+where
+    T: Std140ArrayElement,
+{
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut(self.0.iter_mut())
+    }
+}
+
+/// An iterator that moves the elements out of an [array][struct@array], unwrapped from their
+/// [AlignmentedElement].
+#[derive(Clone)]
+pub struct IntoIter<T, const LEN: usize>(::std::array::IntoIter<AlignmentedElement<T>, LEN>)
+where
+    T: Std140ArrayElement;
+
+impl<T, const LEN: usize> Iterator for IntoIter<T, { LEN }>
+where
+    T: Std140ArrayElement,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|element| element.0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T, const LEN: usize> DoubleEndedIterator for IntoIter<T, { LEN }>
+where
+    T: Std140ArrayElement,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|element| element.0)
+    }
+}
+
+impl<T, const LEN: usize> ExactSizeIterator for IntoIter<T, { LEN }> where T: Std140ArrayElement {}
+
+impl<T, const LEN: usize> FusedIterator for IntoIter<T, { LEN }> where T: Std140ArrayElement {}
+
+/// An iterator over references to the elements of an [array][struct@array], unwrapped from their
+/// [AlignmentedElement].
+#[derive(Clone)]
+pub struct Iter<'a, T>(slice::Iter<'a, AlignmentedElement<T>>)
+where
+    T: Std140ArrayElement;
+
+impl<'a, T> Iterator for Iter<'a, T>
+where
+    T: Std140ArrayElement,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|element| &element.0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T>
+where
+    T: Std140ArrayElement,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|element| &element.0)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> where T: Std140ArrayElement {}
+
+impl<T> FusedIterator for Iter<'_, T> where T: Std140ArrayElement {}
+
+/// An iterator over mutable references to the elements of an [array][struct@array], unwrapped
+/// from their [AlignmentedElement].
+pub struct IterMut<'a, T>(slice::IterMut<'a, AlignmentedElement<T>>)
+where
+    T: Std140ArrayElement;
+
+impl<'a, T> Iterator for IterMut<'a, T>
+where
+    T: Std140ArrayElement,
+{
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|element| &mut element.0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T>
+where
+    T: Std140ArrayElement,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|element| &mut element.0)
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> where T: Std140ArrayElement {}
+
+impl<T> FusedIterator for IterMut<'_, T> where T: Std140ArrayElement {}
+
+impl<T, const LEN: usize> array<T, { LEN }>
+where
+    T: Std140ArrayElement,
+{
+    const TYPE_NAME: &'static TypeName = &TypeName::array(T::GLSL_NAME, Some(LEN));
+}
+
+impl<T, const LEN: usize> GlslType for array<T, { LEN }>
+where
+    T: Std140ArrayElement,
+{
+    const GLSL_NAME: &'static str = Self::TYPE_NAME.as_str();
+}
+
+unsafe impl<T, const LEN: usize> ReprStd140 for array<T, { LEN }>
+where
+    T: Std140ArrayElement,
+{
+    const STD140_KIND: MemberKind = {
+        let () = Self::NONZERO_LEN;
+
+        MemberKind::Array
+    };
+    const STD140_PADDING: usize = LEN
+        * (::std::mem::size_of::<AlignmentedElement<T>>() - ::std::mem::size_of::<T>()
+            + T::STD140_PADDING);
+
+    fn write_std140_bytes(&self, bytes: &mut [u8], order: ByteOrder) {
+        assert_eq!(bytes.len(), ::std::mem::size_of::<Self>());
+
+        let stride = ::std::mem::size_of::<AlignmentedElement<T>>();
+        for (element, bytes) in self.0.iter().zip(bytes.chunks_exact_mut(stride)) {
+            element
+                .0
+                .write_std140_bytes(&mut bytes[..::std::mem::size_of::<T>()], order);
+        }
+    }
+}
+
+unsafe impl<T, const LEN: usize> Std140ArrayElement for array<T, { LEN }> where T: Std140ArrayElement
+{}
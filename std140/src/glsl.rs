@@ -0,0 +1,163 @@
+//! GLSL type names for introspection and code generation.
+
+/// A type with a GLSL type name.
+///
+/// Implemented for every [ReprStd140][crate::ReprStd140] type, which requires it. Arrays are named
+/// after their element type followed by their dimensions (`"uint[2][3]"`) and structs marked with
+/// [`#[repr_std140]`][crate::repr_std140] by their Rust name. Useful as a bound for code that only
+/// needs the name, e.g. for logging.
+///
+/// # Example
+///
+/// ```
+/// use std140::{array::array, glsl::GlslType, mat::mat4x4, uint};
+///
+/// fn describe<T: GlslType>(name: &str) -> String {
+///     format!("{} {};", T::GLSL_NAME, name)
+/// }
+///
+/// assert_eq!(describe::<mat4x4>("transform"), "mat4 transform;");
+/// assert_eq!(<array<array<uint, 3>, 2> as GlslType>::GLSL_NAME, "uint[2][3]");
+/// ```
+pub trait GlslType {
+    /// The name of the GLSL type this type represents.
+    const GLSL_NAME: &'static str;
+}
+
+/// The maximum length in bytes of a GLSL type name composed at compile time.
+const MAX_LEN: usize = 128;
+
+/// A GLSL type name composed at compile time, e.g. the name of an array type from the name of its
+/// element type.
+pub(crate) struct TypeName {
+    bytes: [u8; MAX_LEN],
+    len: usize,
+}
+
+impl TypeName {
+    /// Composes the name of an array of `element` with `len` elements, or of an unbounded array of
+    /// `element` if `len` is `None`.
+    ///
+    /// The new dimension is inserted before any dimensions of `element` itself, such that an array
+    /// of 2 `uint[3]` arrays is named `uint[2][3]`, as in GLSL.
+    pub(crate) const fn array(element: &str, len: Option<usize>) -> Self {
+        let element = element.as_bytes();
+        let mut name = TypeName {
+            bytes: [0; MAX_LEN],
+            len: 0,
+        };
+
+        let mut i = 0;
+        while i < element.len() && element[i] != b'[' {
+            name = name.push(element[i]);
+            i += 1;
+        }
+
+        name = name.push(b'[');
+
+        if let Some(len) = len {
+            let mut digits = [0; 20];
+            let mut count = 0;
+            let mut rest = len;
+
+            loop {
+                digits[count] = b'0' + (rest % 10) as u8;
+                count += 1;
+                rest /= 10;
+
+                if rest == 0 {
+                    break;
+                }
+            }
+
+            while count > 0 {
+                count -= 1;
+                name = name.push(digits[count]);
+            }
+        }
+
+        name = name.push(b']');
+
+        while i < element.len() {
+            name = name.push(element[i]);
+            i += 1;
+        }
+
+        name
+    }
+
+    const fn push(mut self, byte: u8) -> Self {
+        assert!(self.len < MAX_LEN, "GLSL type name is too long");
+
+        self.bytes[self.len] = byte;
+        self.len += 1;
+        self
+    }
+
+    pub(crate) const fn as_str(&self) -> &str {
+        match ::std::str::from_utf8(self.bytes.split_at(self.len).0) {
+            Ok(name) => name,
+            Err(_) => panic!("GLSL type name is not valid UTF-8"),
+        }
+    }
+}
+
+/// Splits the GLSL type name `name` into the base type name and the array dimensions, e.g.
+/// `("PointLight", "[2]")` for `"PointLight[2]"`.
+#[doc(hidden)]
+pub const fn split_array_dims(name: &str) -> (&str, &str) {
+    let bytes = name.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() && bytes[i] != b'[' {
+        i += 1;
+    }
+
+    let (base, dims) = bytes.split_at(i);
+
+    (from_utf8(base), from_utf8(dims))
+}
+
+/// Returns the total length of `pieces` in bytes.
+#[doc(hidden)]
+pub const fn concat_len(pieces: &[&str]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < pieces.len() {
+        len += pieces[i].len();
+        i += 1;
+    }
+
+    len
+}
+
+/// Concatenates `pieces`, whose total length must be `LEN`, at compile time.
+#[doc(hidden)]
+pub const fn concat<const LEN: usize>(pieces: &[&str]) -> [u8; LEN] {
+    let mut bytes = [0; LEN];
+    let mut len = 0;
+    let mut i = 0;
+    while i < pieces.len() {
+        let piece = pieces[i].as_bytes();
+        let mut j = 0;
+        while j < piece.len() {
+            bytes[len] = piece[j];
+            len += 1;
+            j += 1;
+        }
+
+        i += 1;
+    }
+
+    assert!(len == LEN, "concatenated length does not match");
+
+    bytes
+}
+
+/// Converts bytes concatenated from string pieces back into a string.
+#[doc(hidden)]
+pub const fn from_utf8(bytes: &[u8]) -> &str {
+    match ::std::str::from_utf8(bytes) {
+        Ok(string) => string,
+        Err(_) => panic!("concatenated string is not valid UTF-8"),
+    }
+}
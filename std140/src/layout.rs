@@ -0,0 +1,200 @@
+//! Types that describe the std140 memory layout of structs marked with
+//! [`#[repr_std140]`][repr_std140].
+//!
+//! [repr_std140]: ../attr.repr_std140.html
+
+use ::std::fmt;
+
+/// Describes the std140 memory layout of a struct marked with [`#[repr_std140]`][repr_std140].
+///
+/// Available for every such struct as [Std140Struct::STD140_LAYOUT][crate::Std140Struct].
+///
+/// [repr_std140]: ../attr.repr_std140.html
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Layout {
+    /// The size of the struct in bytes, including any trailing padding.
+    pub size: usize,
+
+    /// The alignment of the struct in bytes.
+    pub align: usize,
+
+    /// The members of the struct, in declaration order.
+    pub members: &'static [Member],
+}
+
+impl Layout {
+    /// Returns the number of bytes between the end of the member at `index` and the start of the
+    /// next member, or the end of the struct for the last member.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub const fn padding_after(&self, index: usize) -> usize {
+        let member = &self.members[index];
+        let next_offset = if index + 1 < self.members.len() {
+            self.members[index + 1].offset
+        } else {
+            self.size
+        };
+
+        next_offset - (member.offset + member.size)
+    }
+
+    /// Returns the total number of bytes of the struct that are padding rather than data, both
+    /// between and inside its members.
+    pub const fn padding(&self) -> usize {
+        let mut padding = if self.members.is_empty() {
+            self.size
+        } else {
+            self.members[0].offset
+        };
+
+        let mut index = 0;
+        while index < self.members.len() {
+            padding += self.members[index].padding + self.padding_after(index);
+            index += 1;
+        }
+
+        padding
+    }
+}
+
+/// Describes a single member (field) of a struct marked with [`#[repr_std140]`][repr_std140].
+///
+/// [repr_std140]: ../attr.repr_std140.html
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Member {
+    /// The name of the field; the index of the field for tuple structs.
+    pub name: &'static str,
+
+    /// The offset of the member in bytes, relative to the start of the struct.
+    pub offset: usize,
+
+    /// The size of the member in bytes.
+    pub size: usize,
+
+    /// The alignment of the member in bytes.
+    pub align: usize,
+
+    /// The number of bytes inside the member that are padding, see
+    /// [ReprStd140::STD140_PADDING][crate::ReprStd140::STD140_PADDING].
+    pub padding: usize,
+
+    /// The kind of GLSL type the member represents.
+    pub kind: MemberKind,
+
+    /// The name of the GLSL type the member represents, see
+    /// [GlslType::GLSL_NAME][crate::glsl::GlslType::GLSL_NAME], e.g. to cross-check the
+    /// layout against shader reflection data.
+    pub glsl_type: &'static str,
+}
+
+/// The kind of GLSL type a struct member represents.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum MemberKind {
+    /// A scalar, e.g. `float` or `uint`.
+    Scalar,
+
+    /// A vector, e.g. `vec3` or `bvec2`.
+    Vector,
+
+    /// A matrix, e.g. `mat4`.
+    Matrix,
+
+    /// An array of any other kind.
+    Array,
+
+    /// A struct marked with [`#[repr_std140]`][repr_std140].
+    ///
+    /// [repr_std140]: ../attr.repr_std140.html
+    Struct,
+}
+
+/// A printable report of the padding in the std140 layout of a struct.
+///
+/// std140 aligns many types to 16 bytes, so a struct can easily end up with a large share of
+/// padding, e.g. two consecutive `vec3` members each take 16 bytes of which 4 are unused, and a
+/// `float` followed by a `vec4` is followed by 12 unused bytes. The report lists where padding
+/// occurs, which helps to decide whether reordering or combining members is worthwhile.
+///
+/// Generated for structs marked with `#[repr_std140(warn_padding)]` as their `PADDING_REPORT`
+/// const, but can be created for any struct from its layout.
+///
+/// # Example
+///
+/// ```
+/// use std140::{layout::PaddingReport, Std140Struct};
+///
+/// #[std140::repr_std140]
+/// struct Light {
+///     intensity: std140::float,
+///     color: std140::vec::vec4,
+/// }
+///
+/// let report = PaddingReport::new("Light", Light::STD140_LAYOUT);
+///
+/// assert_eq!(report.padding(), 12);
+/// println!("{}", report);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PaddingReport {
+    name: &'static str,
+    layout: Layout,
+}
+
+impl PaddingReport {
+    /// Creates a report for the struct named `name` with the given layout.
+    pub const fn new(name: &'static str, layout: Layout) -> Self {
+        PaddingReport { name, layout }
+    }
+
+    /// Returns the total number of padding bytes in the struct.
+    pub const fn padding(&self) -> usize {
+        self.layout.padding()
+    }
+
+    /// Returns the layout the report describes.
+    pub const fn layout(&self) -> &Layout {
+        &self.layout
+    }
+}
+
+impl fmt::Display for PaddingReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let padding = self.padding();
+
+        writeln!(
+            f,
+            "{}: {} of {} bytes are padding",
+            self.name, padding, self.layout.size
+        )?;
+
+        if let Some(first) = self.layout.members.first() {
+            if first.offset > 0 {
+                writeln!(f, "  {} bytes before `{}`", first.offset, first.name)?;
+            }
+        }
+
+        for (index, member) in self.layout.members.iter().enumerate() {
+            if member.padding > 0 {
+                writeln!(f, "  {} bytes inside `{}`", member.padding, member.name)?;
+            }
+
+            let after = self.layout.padding_after(index);
+
+            if after > 0 {
+                writeln!(f, "  {} bytes after `{}`", after, member.name)?;
+            }
+        }
+
+        if padding > 0 {
+            writeln!(
+                f,
+                "  consider ordering members from largest to smallest alignment, or combining \
+                 scalars and small vectors into a vec4"
+            )?;
+        }
+
+        Ok(())
+    }
+}
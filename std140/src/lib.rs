@@ -81,6 +81,17 @@
 //! Note that although the field names match the block member names in this example, this is not
 //! strictly necessary: only pairwise field-type compatibility is required.
 //!
+//! # Optional features
+//!
+//! - `mint`: adds conversions from [mint](https://docs.rs/mint) types, such as
+//!   [mat::mat4x4::from_mint_quaternion].
+//! - `rayon`: adds `unbounded_array::par_from_fn`, computing the elements of large arrays in
+//!   parallel with [rayon](https://docs.rs/rayon).
+//! - `serde`: implements `Serialize` and `Deserialize` for the scalar, vector and matrix types and
+//!   unbounded arrays; see the [serde] module.
+//! - `wgpu`: adds `wgpu::Std140BufferExt`, creating [wgpu](https://docs.rs/wgpu) buffers from std140
+//!   structs. Targets wgpu 30.
+//!
 //! [repr_std140]: attr.repr_std140.html
 
 /// Attribute macro that can be applied to a struct to ensure its representation is compatible with
@@ -90,6 +101,21 @@
 ///
 /// Any struct marked with this attribute will automatically implement [Std140Struct]
 ///
+/// Both structs with named fields and tuple structs are supported; unit structs and other structs
+/// without fields are rejected, as an empty interface block has no std140 representation.
+///
+/// Like every std140 struct, a marked struct is aligned to 16 bytes and its size is rounded up to a
+/// multiple of 16. This includes single-field newtypes such as `struct Meters(std140::float)`, which
+/// can therefore be used as fields of other marked structs with the layout GLSL expects for a
+/// nested struct member.
+///
+/// Other attributes on the struct, such as `#[derive(Clone, Copy)]`, are preserved and may be
+/// placed either before or after this attribute. Note that the std140 [array!] repeat form requires
+/// the element type to implement [Copy].
+///
+/// Fields may be gated with `#[cfg(...)]`; fields removed by cfg evaluation are left out of the
+/// layout and of everything generated from it.
+///
 /// # Example
 ///
 /// ```rust
@@ -99,30 +125,912 @@
 ///     intensity: std140::float,
 /// }
 /// ```
+///
+/// # Arguments
+///
+/// The attribute optionally takes a comma-separated list of arguments:
+///
+/// - `builder`: generates a `with_<field>` method for every field, which sets the field and returns
+///   the updated struct (for tuple structs the methods are named after the field index, e.g.
+///   `with_0`).
+///
+/// ```rust
+/// #[std140::repr_std140(builder)]
+/// struct PointLight {
+///     position: std140::vec::vec3,
+///     intensity: std140::float,
+/// }
+///
+/// let light = PointLight {
+///     position: std140::vec::vec3::zero(),
+///     intensity: std140::float(0.0),
+/// }
+/// .with_intensity(std140::float(0.5));
+/// ```
+///
+/// - `glsl`: generates a `GLSL_DECL` const holding the declaration of a GLSL uniform block
+///   matching the struct. Field types are declared by their [GlslType] name, e.g. `mat4x4` becomes
+///   `mat4` and `array<PointLight, 2>` becomes `PointLight lights[2]`; structs marked with this
+///   attribute must be declared separately on the GLSL side. Tuple struct fields are named after
+///   their index, e.g. `_0`. Fields of type
+///   [unbounded_array][unbounded_array::unbounded_array] are rejected, as uniform blocks can't
+///   contain runtime-sized arrays.
+///
+/// ```rust
+/// #[std140::repr_std140]
+/// struct PointLight {
+///     position: std140::vec::vec3,
+///     intensity: std140::float,
+/// }
+///
+/// #[std140::repr_std140(glsl)]
+/// struct Uniforms {
+///     transform: std140::mat::mat4x4,
+///     lights: std140::array::array<PointLight, 2>,
+/// }
+///
+/// assert_eq!(
+///     Uniforms::GLSL_DECL,
+///     "layout(std140) uniform Uniforms {\n    mat4 transform;\n    PointLight lights[2];\n};\n"
+/// );
+/// ```
+///
+/// - `warn_padding`: generates a `PADDING_REPORT` const, a [layout::PaddingReport] listing the
+///   padding std140 inserts between and inside the fields. Procedural macros can't emit warnings,
+///   so print the report to find out whether reordering or combining fields is worthwhile.
+///
+/// ```rust
+/// #[std140::repr_std140(warn_padding)]
+/// struct Uniforms {
+///     position: std140::vec::vec3,
+///     normal: std140::vec::vec3,
+/// }
+///
+/// assert_eq!(Uniforms::PADDING_REPORT.padding(), 8);
+/// println!("{}", Uniforms::PADDING_REPORT);
+/// ```
+///
+/// - `wrap_arrays`: rewrites fields of Rust array types such as `[std140::float; 4]`, whose
+///   elements are not padded to 16 bytes, to the matching std140 [array][crate::array::array]
+///   types, e.g. `array<std140::float, 4>`. Nested arrays are rewritten as well. The rewritten field
+///   must be initialized with an std140 array, e.g. using the [array!] macro.
+///
+/// ```rust
+/// #[std140::repr_std140(wrap_arrays)]
+/// struct Uniforms {
+///     weights: [std140::float; 4],
+/// }
+///
+/// let uniforms = Uniforms {
+///     weights: std140::array![std140::float(0.25); 4],
+/// };
+///
+/// assert_eq!(std::mem::size_of::<Uniforms>(), 64);
+/// ```
+///
+/// - `wgsl`: generates a `wgsl_definition` function returning the definition of a WGSL struct
+///   matching the struct, e.g. for a uniform buffer used with WebGPU. Field types are mapped by
+///   name, e.g. `vec3` becomes `vec3<f32>` and `boolean` becomes `u32`, as WGSL booleans can't be
+///   stored in buffers. Every member is annotated with the alignment and size of its std140 slot,
+///   as WGSL packs some types more tightly. Matrices with 2 rows and arrays of elements smaller than
+///   16 bytes are laid out differently in WGSL and are rejected.
+///
+/// ```rust
+/// #[std140::repr_std140(wgsl)]
+/// struct Uniforms {
+///     position: std140::vec::vec3,
+///     intensity: std140::float,
+/// }
+///
+/// assert_eq!(
+///     Uniforms::wgsl_definition(),
+///     "struct Uniforms {\n    @align(16) @size(16) position: vec3<f32>,\n    @align(4) @size(16) intensity: f32,\n}\n"
+/// );
+/// ```
+///
+/// - `mirror(Type)`: implements `From` in both directions between the struct and `Type`, a plain
+///   struct with the same fields holding the [plain counterparts][plain::PlainRepr] of the field
+///   types, e.g. `f32` for [float] and `[f32; 3]` for [vec::vec3]. The struct also implements
+///   [plain::PlainRepr] with `Type` as its counterpart, so mirrored structs can be nested.
+///
+/// ```rust
+/// #[derive(Clone, Copy, PartialEq, Debug)]
+/// struct Light {
+///     position: [f32; 3],
+///     intensity: f32,
+///     enabled: bool,
+/// }
+///
+/// #[std140::repr_std140(mirror(Light))]
+/// struct LightUniforms {
+///     position: std140::vec::vec3,
+///     intensity: std140::float,
+///     enabled: std140::boolean,
+/// }
+///
+/// let light = Light {
+///     position: [0.0, 2.0, 0.0],
+///     intensity: 0.5,
+///     enabled: true,
+/// };
+/// let uniforms = LightUniforms::from(light);
+///
+/// assert_eq!(uniforms.intensity, std140::float(0.5));
+/// assert_eq!(Light::from(uniforms), light);
+/// ```
+///
+/// - `view = Name`: like `mirror(Type)`, but also generates the plain struct `Name` with the same
+///   visibility and fields as the struct, each holding the [plain counterpart][plain::PlainRepr] of
+///   its field type. The generated struct derives `Clone`, `Copy`, `PartialEq` and `Debug`. Cannot
+///   be combined with `mirror(Type)` or used on generic structs.
+///
+/// ```rust
+/// #[std140::repr_std140(view = CameraView)]
+/// struct CameraUniforms {
+///     position: std140::vec::vec3,
+///     fov: std140::float,
+/// }
+///
+/// let uniforms = CameraUniforms::from(CameraView {
+///     position: [0.0, 1.0, 5.0],
+///     fov: 60.0,
+/// });
+///
+/// assert_eq!(uniforms.fov, std140::float(60.0));
+/// assert_eq!(CameraView::from(uniforms).position, [0.0, 1.0, 5.0]);
+/// ```
+///
+/// - `binding = N` and `set = N`: generate `BINDING` and `SET` consts of type `u32`, recording the
+///   binding point and descriptor set of the uniform block at the type level, e.g. for building
+///   descriptor set layouts. Either may be given without the other.
+///
+/// ```rust
+/// #[std140::repr_std140(binding = 2, set = 0)]
+/// struct Uniforms {
+///     transform: std140::mat::mat4x4,
+/// }
+///
+/// assert_eq!(Uniforms::BINDING, 2);
+/// assert_eq!(Uniforms::SET, 0);
+/// ```
+///
+/// - `as_ref`: implements `AsRef<[u8]>`, viewing the struct's memory as bytes for APIs generic over
+///   byte buffers without copying. Padding bytes are uninitialized and must never be read, so this
+///   is only available for structs without any padding, between or inside their fields; compilation
+///   fails otherwise. Use [Std140Struct::to_std140_bytes_le] for structs with padding.
+///
+/// ```rust
+/// #[std140::repr_std140(as_ref)]
+/// struct Uniforms {
+///     transform: std140::mat::mat4x4,
+///     color: std140::vec::vec4,
+/// }
+///
+/// fn byte_len(bytes: impl AsRef<[u8]>) -> usize {
+///     bytes.as_ref().len()
+/// }
+///
+/// let uniforms = Uniforms {
+///     transform: std140::mat::mat4x4::identity(),
+///     color: std140::vec::vec4(1.0, 1.0, 1.0, 1.0),
+/// };
+///
+/// assert_eq!(byte_len(&uniforms), 80);
+/// ```
+///
+/// ```compile_fail
+/// #[std140::repr_std140(as_ref)]
+/// struct Uniforms {
+///     position: std140::vec::vec3,
+/// }
+/// ```
 pub use std140_macros::repr_std140;
 
+use ::std::{
+    fmt,
+    iter::{Product, Sum},
+    mem,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Not, Sub, SubAssign},
+};
+
+use glsl::GlslType;
+use layout::{Layout, MemberKind};
+
 pub mod array;
+pub mod glsl;
+pub mod layout;
 pub mod mat;
+#[cfg(feature = "mint")]
+mod mint;
+pub mod plain;
+#[cfg(feature = "serde")]
+pub mod serde;
 pub mod unbounded_array;
 pub mod vec;
+#[cfg(feature = "wgpu")]
+pub mod wgpu;
 
 /// Marker trait for types that can be used as fields in structs marked with
 /// [`#[repr_std140]`][repr_std140].
 ///
+/// The name of the GLSL type a type represents is given by its [GlslType] implementation.
+///
+/// # Safety
+///
+/// Implementing types must have a memory layout that matches the std140 layout of the
+/// corresponding GLSL type. [STD140_PADDING][Self::STD140_PADDING] must be exactly the number of
+/// padding bytes in that layout, and types with padding must override
+/// [write_std140_bytes][Self::write_std140_bytes] so that it never reads them.
+///
 /// [repr_std140]: attr.repr_std140.html
-pub unsafe trait ReprStd140 {}
+pub unsafe trait ReprStd140: GlslType {
+    /// The kind of GLSL type this type represents when used as a block member.
+    const STD140_KIND: MemberKind;
+
+    /// The number of bytes of a value of this type that are padding rather than data.
+    ///
+    /// For example, a `vec3` occupies 16 bytes of which 4 are padding, and each column of a
+    /// `mat2x2` is padded from 8 to 16 bytes.
+    const STD140_PADDING: usize;
+
+    /// The size in bytes of the scalars making up a value of this type, which are swapped as a
+    /// whole when writing the value in a byte order other than the host's.
+    ///
+    /// All built-in types consist of 4 byte scalars; a double precision type would use 8.
+    const STD140_SCALAR_SIZE: usize = 4;
 
-/// Marker trait for types that can be used as the element type for std140 [array][struct@array]s.
+    /// Writes the std140 representation of `self` into `bytes`, storing every scalar in the byte
+    /// order `order`.
+    ///
+    /// Only the bytes holding data are written and padding bytes are left untouched, so writing
+    /// into a zeroed buffer yields a representation with zeroed padding. Unlike viewing the value's
+    /// memory as bytes, this never reads padding bytes, which hold no defined value.
+    ///
+    /// The default implementation copies the memory of `self`, which is only sound for types
+    /// without padding; using it for a type with a nonzero [STD140_PADDING][Self::STD140_PADDING]
+    /// fails to compile, so such types must override this method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is not exactly `size_of::<Self>()` bytes long.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::{vec::vec3, ByteOrder, ReprStd140};
+    ///
+    /// let mut bytes = [0; 16];
+    /// vec3(1.0, 2.0, 3.0).write_std140_bytes(&mut bytes, ByteOrder::Big);
+    ///
+    /// assert_eq!(bytes[4..8], 2.0f32.to_be_bytes());
+    /// assert_eq!(bytes[12..], [0; 4]);
+    /// ```
+    fn write_std140_bytes(&self, bytes: &mut [u8], order: ByteOrder)
+    where
+        Self: Sized,
+    {
+        const {
+            assert!(
+                Self::STD140_PADDING == 0,
+                "types with padding must override ReprStd140::write_std140_bytes"
+            )
+        };
+
+        write_scalars(bytes, bytes_of(self), Self::STD140_SCALAR_SIZE, order);
+    }
+}
+
+/// The order of the bytes of each scalar in a std140 byte representation.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ByteOrder {
+    /// The least significant byte comes first, as on virtually all hosts that run GPUs.
+    Little,
+
+    /// The most significant byte comes first.
+    Big,
+}
+
+impl ByteOrder {
+    /// The byte order of the host.
+    pub const NATIVE: ByteOrder = if cfg!(target_endian = "little") {
+        ByteOrder::Little
+    } else {
+        ByteOrder::Big
+    };
+}
+
+/// Marker trait for types that can be used as the element type for std140 [array][crate::array::array]s.
+///
+/// # Safety
+///
+/// Implementing types must remain std140 compatible when aligned to 16 bytes, as every array
+/// element is.
 pub unsafe trait Std140ArrayElement: ReprStd140 {}
 
 /// Marker trait for struct types that were marked with [`#[repr_std140]`][repr_std140].
 ///
+/// # Safety
+///
+/// Should not be implemented manually; use the [`#[repr_std140]`][repr_std140] attribute instead.
+///
 /// [repr_std140]: attr.repr_std140.html
-pub unsafe trait Std140Struct {}
+pub unsafe trait Std140Struct {
+    /// Describes the std140 layout of the struct and each of its fields.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::{layout::MemberKind, Std140Struct};
+    ///
+    /// #[std140::repr_std140]
+    /// struct Uniforms {
+    ///     transform: std140::mat::mat4x4,
+    ///     ambient_light_color: std140::vec::vec3,
+    ///     intensity: std140::float,
+    /// }
+    ///
+    /// let layout = Uniforms::STD140_LAYOUT;
+    ///
+    /// assert_eq!(layout.size, 96);
+    /// assert_eq!(layout.members[1].name, "ambient_light_color");
+    /// assert_eq!(layout.members[1].offset, 64);
+    /// assert_eq!(layout.members[2].kind, MemberKind::Scalar);
+    /// ```
+    const STD140_LAYOUT: Layout;
+
+    /// Writes every field into its range of `bytes`, see [ReprStd140::write_std140_bytes].
+    ///
+    /// Generated by [`#[repr_std140]`][repr_std140].
+    ///
+    /// [repr_std140]: attr.repr_std140.html
+    #[doc(hidden)]
+    fn write_std140_fields(&self, bytes: &mut [u8], order: ByteOrder);
+
+    /// The name of the struct, which is also its GLSL type name.
+    const STRUCT_NAME: &'static str;
+
+    /// The distance in bytes between consecutive elements of an std140 array of this struct, e.g.
+    /// to compute the offset of an element when writing into a storage buffer.
+    ///
+    /// Array elements are aligned to 16 bytes, so this is the struct's size rounded up to a
+    /// multiple of 16. As std140 structs are already padded to a multiple of 16 bytes, it always
+    /// equals the struct's size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::Std140Struct;
+    ///
+    /// #[std140::repr_std140]
+    /// struct PointLight {
+    ///     position: std140::vec::vec3,
+    ///     intensity: std140::float,
+    /// }
+    ///
+    /// let third_light_offset = 2 * PointLight::STD140_ARRAY_STRIDE;
+    ///
+    /// assert_eq!(third_light_offset, 64);
+    /// ```
+    const STD140_ARRAY_STRIDE: usize = align_up(Self::STD140_LAYOUT.size, 16);
+
+    /// Returns a pointer to the start of the struct's std140 representation, e.g. to pass to a
+    /// graphics API.
+    ///
+    /// The pointer is aligned to 16 bytes like the struct itself. Padding bytes behind the pointer
+    /// are uninitialized, so only hand the pointer to code that copies the bytes without inspecting
+    /// them; use [Std140Struct::to_std140_bytes] to read the bytes on the CPU.
+    fn as_ptr(&self) -> *const u8
+    where
+        Self: Sized,
+    {
+        self as *const Self as *const u8
+    }
+
+    /// Returns a copy of the struct's std140 representation in host byte order, ready to be
+    /// uploaded.
+    ///
+    /// Padding bytes are zeroed, so equal structs always produce equal bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::Std140Struct;
+    ///
+    /// #[std140::repr_std140]
+    /// struct Uniforms {
+    ///     position: std140::vec::vec3,
+    ///     intensity: std140::float,
+    /// }
+    ///
+    /// let uniforms = Uniforms {
+    ///     position: std140::vec::vec3(1.0, 0.0, 0.0),
+    ///     intensity: std140::float(1.0),
+    /// };
+    /// let bytes = uniforms.to_std140_bytes();
+    ///
+    /// assert_eq!(bytes.len(), 32);
+    /// assert_eq!(bytes[..4], 1.0f32.to_ne_bytes());
+    /// assert_eq!(bytes[12..16], [0; 4]);
+    /// ```
+    fn to_std140_bytes(&self) -> Vec<u8>
+    where
+        Self: Sized,
+    {
+        std140_bytes_of(self, ByteOrder::NATIVE)
+    }
+
+    /// Returns a copy of the struct's std140 representation with every scalar stored in
+    /// little-endian byte order.
+    ///
+    /// The members are written one scalar at a time, so scalars of any size are swapped correctly on
+    /// big-endian hosts. Padding bytes are zeroed.
+    fn to_std140_bytes_le(&self) -> Vec<u8>
+    where
+        Self: Sized,
+    {
+        std140_bytes_of(self, ByteOrder::Little)
+    }
+
+    /// Returns a copy of the struct's std140 representation with every scalar stored in big-endian
+    /// byte order.
+    ///
+    /// Padding bytes are zeroed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::Std140Struct;
+    ///
+    /// #[std140::repr_std140]
+    /// struct Uniforms {
+    ///     light_count: std140::uint,
+    /// }
+    ///
+    /// let uniforms = Uniforms {
+    ///     light_count: std140::uint(1),
+    /// };
+    ///
+    /// assert_eq!(uniforms.to_std140_bytes_be()[..4], [0, 0, 0, 1]);
+    /// ```
+    fn to_std140_bytes_be(&self) -> Vec<u8>
+    where
+        Self: Sized,
+    {
+        std140_bytes_of(self, ByteOrder::Big)
+    }
+
+    /// Compares the std140 representation of this struct to that of another, possibly different,
+    /// std140 struct type.
+    ///
+    /// Returns `true` if both structs have the same size and members at the same offsets with the
+    /// same sizes, and the bytes of every member are equal. Member names and types are not
+    /// compared, which allows comparing structurally identical struct definitions, e.g. while
+    /// migrating from one version of a uniform block definition to the next.
+    ///
+    /// Padding is never read: padding between members, at the end of the struct and inside
+    /// members, such as the padding of `vec3`s, array elements or nested structs, is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::Std140Struct;
+    ///
+    /// #[std140::repr_std140]
+    /// struct LightV1 {
+    ///     intensity: std140::float,
+    ///     color: std140::vec::vec4,
+    /// }
+    ///
+    /// #[std140::repr_std140]
+    /// struct LightV2 {
+    ///     brightness: std140::float,
+    ///     tint: std140::vec::vec4,
+    /// }
+    ///
+    /// let v1 = LightV1 {
+    ///     intensity: std140::float(0.5),
+    ///     color: std140::vec::vec4(1.0, 1.0, 1.0, 1.0),
+    /// };
+    /// let v2 = LightV2 {
+    ///     brightness: std140::float(0.5),
+    ///     tint: std140::vec::vec4(1.0, 1.0, 1.0, 1.0),
+    /// };
+    ///
+    /// assert!(v1.layout_eq(&v2));
+    /// ```
+    fn layout_eq<O>(&self, other: &O) -> bool
+    where
+        Self: Sized,
+        O: Std140Struct,
+    {
+        let layout = Self::STD140_LAYOUT;
+        let other_layout = O::STD140_LAYOUT;
+
+        if layout.size != other_layout.size || layout.members.len() != other_layout.members.len() {
+            return false;
+        }
+
+        let bytes = std140_bytes_of(self, ByteOrder::NATIVE);
+        let other_bytes = std140_bytes_of(other, ByteOrder::NATIVE);
+
+        layout
+            .members
+            .iter()
+            .zip(other_layout.members)
+            .all(|(member, other_member)| {
+                let range = member.offset..member.offset + member.size;
+
+                member.offset == other_member.offset
+                    && member.size == other_member.size
+                    && bytes[range.clone()] == other_bytes[range]
+            })
+    }
+
+    /// Returns a 64-bit FNV-1a hash of the struct's std140 representation, e.g. to skip uploading
+    /// uniforms that did not change since the last frame.
+    ///
+    /// The hash covers the bytes that hold data, with every padding byte hashed as zero, so equal
+    /// structs always fingerprint equally. The hash is not cryptographic, so it only tells changed
+    /// structs apart with high probability.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::Std140Struct;
+    ///
+    /// #[std140::repr_std140]
+    /// struct Uniforms {
+    ///     color: std140::vec::vec4,
+    /// }
+    ///
+    /// let red = Uniforms {
+    ///     color: std140::vec::vec4(1.0, 0.0, 0.0, 1.0),
+    /// };
+    /// let green = Uniforms {
+    ///     color: std140::vec::vec4(0.0, 1.0, 0.0, 1.0),
+    /// };
+    ///
+    /// assert_ne!(red.std140_fingerprint(), green.std140_fingerprint());
+    /// ```
+    fn std140_fingerprint(&self) -> u64
+    where
+        Self: Sized,
+    {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let bytes = std140_bytes_of(self, ByteOrder::NATIVE);
+
+        bytes.iter().fold(OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+        })
+    }
+}
+
+impl<T> GlslType for T
+where
+    T: Std140Struct,
+{
+    const GLSL_NAME: &'static str = T::STRUCT_NAME;
+}
+
+unsafe impl<T> ReprStd140 for T
+where
+    T: Std140Struct,
+{
+    const STD140_KIND: MemberKind = MemberKind::Struct;
+    const STD140_PADDING: usize = T::STD140_LAYOUT.padding();
 
-unsafe impl<T> ReprStd140 for T where T: Std140Struct {}
+    fn write_std140_bytes(&self, bytes: &mut [u8], order: ByteOrder) {
+        assert_eq!(bytes.len(), mem::size_of::<T>());
+
+        self.write_std140_fields(bytes, order);
+    }
+}
 unsafe impl<T> Std140ArrayElement for T where T: Std140Struct {}
 
+/// Types whose std140 representation can be copied into bytes, ready to be uploaded to a GPU buffer.
+///
+/// Implemented for structs marked with [`#[repr_std140]`][repr_std140], the vector and matrix
+/// types, [arrays][crate::array::array] and [unbounded arrays][crate::unbounded_array::unbounded_array],
+/// so upload code can accept any of them as `&impl AsStd140Bytes`. Padding bytes are zeroed.
+///
+/// # Example
+///
+/// ```
+/// use std140::AsStd140Bytes;
+///
+/// fn upload(buffer: &mut Vec<u8>, data: &impl AsStd140Bytes) {
+///     buffer.extend_from_slice(&data.std140_bytes());
+/// }
+///
+/// let mut buffer = Vec::new();
+///
+/// upload(&mut buffer, &std140::vec::vec4(0.0, 0.0, 0.0, 1.0));
+/// upload(&mut buffer, &std140::mat::mat4x4::identity());
+///
+/// assert_eq!(buffer.len(), 80);
+/// ```
+///
+/// [repr_std140]: attr.repr_std140.html
+pub trait AsStd140Bytes {
+    /// Returns a copy of the value's std140 representation in host byte order.
+    fn std140_bytes(&self) -> Vec<u8>;
+}
+
+impl<T> AsStd140Bytes for T
+where
+    T: Std140Struct,
+{
+    fn std140_bytes(&self) -> Vec<u8> {
+        std140_bytes_of(self, ByteOrder::NATIVE)
+    }
+}
+
+macro_rules! impl_as_std140_bytes {
+    ($($name:ty),+) => {
+        $(
+            impl AsStd140Bytes for $name {
+                fn std140_bytes(&self) -> Vec<u8> {
+                    std140_bytes_of(self, ByteOrder::NATIVE)
+                }
+            }
+        )+
+    };
+}
+
+impl_as_std140_bytes!(
+    vec::vec2,
+    vec::vec3,
+    vec::vec4,
+    vec::ivec2,
+    vec::ivec3,
+    vec::ivec4,
+    vec::uvec2,
+    vec::uvec3,
+    vec::uvec4,
+    vec::bvec2,
+    vec::bvec3,
+    vec::bvec4,
+    mat::mat2x2,
+    mat::mat2x3,
+    mat::mat2x4,
+    mat::mat3x2,
+    mat::mat3x3,
+    mat::mat3x4,
+    mat::mat4x2,
+    mat::mat4x3,
+    mat::mat4x4
+);
+
+impl<T, const LEN: usize> AsStd140Bytes for array::array<T, { LEN }>
+where
+    T: Std140ArrayElement,
+{
+    fn std140_bytes(&self) -> Vec<u8> {
+        std140_bytes_of(self, ByteOrder::NATIVE)
+    }
+}
+
+impl<T> AsStd140Bytes for unbounded_array::unbounded_array<T>
+where
+    T: Std140ArrayElement,
+{
+    fn std140_bytes(&self) -> Vec<u8> {
+        self.to_std140_bytes()
+    }
+}
+
+/// Compares the raw bytes of two std140 structs and returns the offset of the first byte that
+/// differs, or `None` if all bytes are equal.
+///
+/// This can help to locate the member responsible for a mismatch between the data on the CPU side
+/// and the data observed on the GPU side, e.g. by looking up the returned offset in the struct's
+/// [STD140_LAYOUT][Std140Struct::STD140_LAYOUT].
+///
+/// Padding bytes are never read; they are treated as zero in both structs, so only the bytes that
+/// hold data can differ.
+///
+/// # Example
+///
+/// ```
+/// #[std140::repr_std140]
+/// struct Uniforms {
+///     color: std140::vec::vec4,
+///     light_count: std140::uint,
+///     material_count: std140::uint,
+///     flags: std140::uint,
+///     mode: std140::uint,
+/// }
+///
+/// let a = Uniforms {
+///     color: std140::vec::vec4(1.0, 1.0, 1.0, 1.0),
+///     light_count: std140::uint(1),
+///     material_count: std140::uint(2),
+///     flags: std140::uint(0),
+///     mode: std140::uint(0),
+/// };
+/// let b = Uniforms {
+///     material_count: std140::uint(3),
+///     ..a
+/// };
+///
+/// assert_eq!(std140::first_byte_diff(&a, &a), None);
+/// assert_eq!(std140::first_byte_diff(&a, &b), Some(20));
+/// ```
+pub fn first_byte_diff<T>(a: &T, b: &T) -> Option<usize>
+where
+    T: Std140Struct,
+{
+    let a = std140_bytes_of(a, ByteOrder::NATIVE);
+    let b = std140_bytes_of(b, ByteOrder::NATIVE);
+
+    a.iter().zip(&b).position(|(a, b)| a != b)
+}
+
+/// Returns `count` copies of `value` laid out like the elements of an std140 array, ready to be
+/// uploaded to a GPU buffer.
+///
+/// Every element occupies the same stride as in an [array][crate::array::array], so the output is
+/// `count` times the element size rounded up to 16 bytes. All padding, both between the elements
+/// and within them, is zeroed.
+///
+/// # Example
+///
+/// ```
+/// let bytes = std140::fill_bytes(3, std140::float(1.0));
+///
+/// assert_eq!(bytes.len(), 48);
+/// assert_eq!(bytes[16..20], 1.0f32.to_ne_bytes());
+/// assert_eq!(bytes[20..32], [0; 12]);
+/// ```
+pub fn fill_bytes<T>(count: usize, value: T) -> Vec<u8>
+where
+    T: Std140ArrayElement + Copy,
+{
+    let stride = mem::size_of::<array::AlignmentedElement<T>>();
+    let mut element = vec![0; stride];
+    value.write_std140_bytes(&mut element[..mem::size_of::<T>()], ByteOrder::NATIVE);
+
+    element.repeat(count)
+}
+
+/// Returns a view on the raw bytes of `value`.
+///
+/// Only used for types without padding, for which every byte is initialized; use
+/// [std140_bytes_of] for all other types.
+fn bytes_of<T>(value: &T) -> &[u8]
+where
+    T: ReprStd140,
+{
+    // SAFETY: the pointer is derived from a valid reference, the length matches the size of the
+    // referenced value and the returned slice borrows from that value. Callers only pass types
+    // without padding, so all bytes are initialized.
+    unsafe { ::std::slice::from_raw_parts(value as *const T as *const u8, mem::size_of::<T>()) }
+}
+
+/// Returns the std140 representation of `value` in byte order `order`, with zeroed padding.
+fn std140_bytes_of<T>(value: &T, order: ByteOrder) -> Vec<u8>
+where
+    T: ReprStd140,
+{
+    let mut bytes = vec![0; mem::size_of::<T>()];
+    value.write_std140_bytes(&mut bytes, order);
+
+    bytes
+}
+
+/// Copies the scalars in `data` into `bytes`, reversing the bytes of every scalar of
+/// `scalar_size` bytes unless `order` is the host's byte order.
+///
+/// # Panics
+///
+/// Panics if `bytes` and `data` differ in length.
+pub(crate) fn write_scalars(bytes: &mut [u8], data: &[u8], scalar_size: usize, order: ByteOrder) {
+    bytes.copy_from_slice(data);
+
+    if order != ByteOrder::NATIVE {
+        for scalar in bytes.chunks_exact_mut(scalar_size) {
+            scalar.reverse();
+        }
+    }
+}
+
+/// Rounds `offset` up to the nearest multiple of `alignment`.
+///
+/// # Panics
+///
+/// Panics if `alignment` is `0`.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(std140::align_up(0, 256), 0);
+/// assert_eq!(std140::align_up(64, 256), 256);
+/// assert_eq!(std140::align_up(300, 16), 304);
+/// ```
+pub const fn align_up(offset: usize, alignment: usize) -> usize {
+    offset.div_ceil(alignment) * alignment
+}
+
+/// Computes the offsets at which a sequence of std140 blocks can be packed into a single buffer.
+///
+/// Every block is placed at the next offset that satisfies both the block type's own alignment and
+/// the minimum alignment the builder was created with. A minimum alignment is typically needed when
+/// binding blocks at dynamic offsets, where graphics APIs require offsets to be a multiple of some
+/// device limit (commonly `256`).
+///
+/// # Example
+///
+/// ```
+/// use std140::{mat::mat4x4, vec::vec3, Std140OffsetBuilder};
+///
+/// let mut builder = Std140OffsetBuilder::with_min_alignment(256);
+///
+/// assert_eq!(builder.push::<mat4x4>(), 0);
+/// assert_eq!(builder.push::<vec3>(), 256);
+/// assert_eq!(builder.offsets(), &[0, 256]);
+/// assert_eq!(builder.size(), 272);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct Std140OffsetBuilder {
+    min_alignment: usize,
+    size: usize,
+    offsets: Vec<usize>,
+}
+
+impl Std140OffsetBuilder {
+    /// Creates a new builder that aligns each block only to its own std140 alignment.
+    pub fn new() -> Self {
+        Self::with_min_alignment(1)
+    }
+
+    /// Creates a new builder that aligns each block to at least `min_alignment` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_alignment` is `0`.
+    pub fn with_min_alignment(min_alignment: usize) -> Self {
+        assert!(min_alignment > 0, "Minimum alignment must not be zero");
+
+        Std140OffsetBuilder {
+            min_alignment,
+            size: 0,
+            offsets: Vec::new(),
+        }
+    }
+
+    /// Appends a block of type `T` and returns the offset at which it was placed.
+    pub fn push<T>(&mut self) -> usize
+    where
+        T: ReprStd140,
+    {
+        let alignment = self.min_alignment.max(mem::align_of::<T>());
+        let offset = align_up(self.size, alignment);
+
+        self.size = offset + mem::size_of::<T>();
+        self.offsets.push(offset);
+
+        offset
+    }
+
+    /// Returns the offsets of all blocks appended so far, in the order they were appended.
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    /// Returns the number of bytes required to hold all blocks appended so far.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl Default for Std140OffsetBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Initializes a `std140` [array][array::array].
 ///
 /// # Example
@@ -133,13 +1041,26 @@ unsafe impl<T> Std140ArrayElement for T where T: Std140Struct {}
 ///     std140::vec::vec2(0.0, 1.0),
 /// ];
 /// ```
+///
+/// An array may also be initialized by repeating a single value, in which case the length may be
+/// any constant expression, including a const generic parameter. As with Rust's own array repeat
+/// expressions, the value's type must implement [Copy]; use [array::array::from_fn] for element
+/// types that don't.
+///
+/// ```
+/// fn zeroes<const N: usize>() -> std140::array::array<std140::float, N> {
+///     std140::array![std140::float(0.0); N]
+/// }
+///
+/// assert_eq!(zeroes::<4>().len(), 4);
+/// ```
 #[macro_export]
 macro_rules! array {
     ($elem:expr; $n:expr) => {
-        $crate::array::array::new([$crate::array::AlignmentedElement($elem); $n])
+        $crate::array::array::new([$crate::array::AlignmentedElement::new($elem); $n])
     };
     ($($x:expr),*) => {
-        $crate::array::array::new([$($crate::array::AlignmentedElement($x) ),*])
+        $crate::array::array::new([$($crate::array::AlignmentedElement::new($x) ),*])
     };
     ($($x:expr,)*) => ($crate::array![$($x),*])
 }
@@ -148,10 +1069,10 @@ macro_rules! array {
 #[macro_export]
 macro_rules! unbounded_array {
     ($t:ty, $elem:expr; $n:expr) => {
-        $crate::unbounded_array::unbounded_array::<$t>::new([$crate::array::AlignmentedElement($elem); $n].to_vec())
+        $crate::unbounded_array::unbounded_array::<$t>::new([$crate::array::AlignmentedElement::new($elem); $n].to_vec())
     };
     ($t:ty, $($x:expr),*) => {
-        $crate::unbounded_array::unbounded_array::<$t>::new([$($crate::array::AlignmentedElement($x) ),*].to_vec())
+        $crate::unbounded_array::unbounded_array::<$t>::new([$($crate::array::AlignmentedElement::new($x) ),*].to_vec())
     };
     ($t:ty, $($x:expr,)*) => ($crate::unbounded_array![$t, $($x),*])
 }
@@ -182,9 +1103,72 @@ mod tests {
 /// ```
 #[repr(C, align(4))]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct float(pub f32);
 
-unsafe impl ReprStd140 for float {}
+impl float {
+    /// Returns the raw bit representation of the value.
+    ///
+    /// Unlike comparisons through [PartialEq], comparing the raw bits is exact: `NaN` values with
+    /// identical payloads compare equal and `0.0` is distinguished from `-0.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(std140::float(1.0).to_bits(), 0x3f80_0000);
+    /// ```
+    pub const fn to_bits(self) -> u32 {
+        self.0.to_bits()
+    }
+
+    /// Creates a value from its raw bit representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(std140::float::from_bits(0x3f80_0000), std140::float(1.0));
+    /// ```
+    pub const fn from_bits(bits: u32) -> Self {
+        float(f32::from_bits(bits))
+    }
+
+    /// Restricts the value to the range `[lo, hi]`, like the GLSL `clamp` function.
+    ///
+    /// A `NaN` value is returned unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo` is greater than `hi`, or if either bound is `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(std140::float(1.5).clamp(0.0, 1.0), std140::float(1.0));
+    /// ```
+    pub fn clamp(self, lo: f32, hi: f32) -> Self {
+        float(self.0.clamp(lo, hi))
+    }
+
+    /// Restricts the value to the range `[0, 1]`, like the HLSL `saturate` function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert_eq!(std140::float(-0.5).saturate(), std140::float(0.0));
+    /// ```
+    pub fn saturate(self) -> Self {
+        self.clamp(0.0, 1.0)
+    }
+}
+
+impl GlslType for float {
+    const GLSL_NAME: &'static str = "float";
+}
+
+unsafe impl ReprStd140 for float {
+    const STD140_KIND: MemberKind = MemberKind::Scalar;
+    const STD140_PADDING: usize = 0;
+}
 unsafe impl Std140ArrayElement for float {}
 
 /// A 32-bit signed integer value.
@@ -194,11 +1178,28 @@ unsafe impl Std140ArrayElement for float {}
 /// ```
 /// let value = std140::int(1);
 /// ```
+///
+/// # Arithmetic
+///
+/// The arithmetic operators follow the semantics of the same operators on [i32]: dividing by
+/// zero panics, and overflow panics in debug builds and wraps in release builds.
+///
+/// ```
+/// assert_eq!(std140::int(6) / std140::int(3), std140::int(2));
+/// ```
 #[repr(C, align(4))]
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct int(pub i32);
 
-unsafe impl ReprStd140 for int {}
+impl GlslType for int {
+    const GLSL_NAME: &'static str = "int";
+}
+
+unsafe impl ReprStd140 for int {
+    const STD140_KIND: MemberKind = MemberKind::Scalar;
+    const STD140_PADDING: usize = 0;
+}
 unsafe impl Std140ArrayElement for int {}
 
 /// A 32-bit unsigned integer value.
@@ -208,13 +1209,163 @@ unsafe impl Std140ArrayElement for int {}
 /// ```
 /// let value = std140::uint(1);
 /// ```
+///
+/// # Arithmetic
+///
+/// The arithmetic operators follow the semantics of the same operators on [u32]: dividing by
+/// zero panics, and overflow panics in debug builds and wraps in release builds.
+///
+/// ```
+/// assert_eq!(std140::uint(6) / std140::uint(3), std140::uint(2));
+/// ```
 #[repr(C, align(4))]
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct uint(pub u32);
 
-unsafe impl ReprStd140 for uint {}
+impl GlslType for uint {
+    const GLSL_NAME: &'static str = "uint";
+}
+
+unsafe impl ReprStd140 for uint {
+    const STD140_KIND: MemberKind = MemberKind::Scalar;
+    const STD140_PADDING: usize = 0;
+}
 unsafe impl Std140ArrayElement for uint {}
 
+macro_rules! impl_scalar_primitive {
+    ($name:ident, $primitive:ty) => {
+        impl From<$primitive> for $name {
+            fn from(value: $primitive) -> Self {
+                $name(value)
+            }
+        }
+
+        impl From<$name> for $primitive {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl PartialEq<$primitive> for $name {
+            fn eq(&self, other: &$primitive) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialEq<$name> for $primitive {
+            fn eq(&self, other: &$name) -> bool {
+                *self == other.0
+            }
+        }
+    };
+}
+
+macro_rules! impl_scalar_const_eq {
+    ($($name:ident: |$a:ident, $b:ident| $eq:expr),+) => {
+        $(
+            impl $name {
+                #[doc = concat!("Compares two `", stringify!($name), "` values in a `const` context, where [PartialEq] is not usable.")]
+                ///
+                /// Floating point values are compared bitwise, so unlike `==` this considers `NaN`
+                /// equal to a `NaN` with the same bits, and `0.0` different from `-0.0`.
+                pub const fn const_eq(&self, other: &Self) -> bool {
+                    let ($a, $b) = (*self, *other);
+
+                    $eq
+                }
+            }
+        )+
+    };
+}
+
+impl_scalar_const_eq!(
+    float: |a, b| a.0.to_bits() == b.0.to_bits(),
+    int: |a, b| a.0 == b.0,
+    uint: |a, b| a.0 == b.0,
+    boolean: |a, b| a as u32 == b as u32,
+    packed_rgba8: |a, b| a.0 == b.0
+);
+
+impl_scalar_primitive!(float, f32);
+impl_scalar_primitive!(int, i32);
+impl_scalar_primitive!(uint, u32);
+
+macro_rules! impl_scalar_op {
+    ($name:ident, $op:ident, $op_fn:ident, $op_assign:ident, $op_assign_fn:ident, $token:tt) => {
+        impl $op for $name {
+            type Output = Self;
+
+            fn $op_fn(self, rhs: Self) -> Self::Output {
+                $name(self.0 $token rhs.0)
+            }
+        }
+
+        impl $op_assign for $name {
+            fn $op_assign_fn(&mut self, rhs: Self) {
+                self.0 = self.0 $token rhs.0;
+            }
+        }
+    };
+}
+
+macro_rules! impl_scalar_arithmetic {
+    ($($name:ident),+) => {
+        $(
+            impl_scalar_op!($name, Add, add, AddAssign, add_assign, +);
+            impl_scalar_op!($name, Sub, sub, SubAssign, sub_assign, -);
+            impl_scalar_op!($name, Mul, mul, MulAssign, mul_assign, *);
+            impl_scalar_op!($name, Div, div, DivAssign, div_assign, /);
+        )+
+    };
+}
+
+impl_scalar_arithmetic!(float, int, uint);
+
+macro_rules! impl_scalar_iter {
+    ($($name:ident($zero:literal, $one:literal)),+) => {
+        $(
+            impl Sum for $name {
+                fn sum<I>(iter: I) -> Self
+                where
+                    I: Iterator<Item = Self>,
+                {
+                    iter.fold($name($zero), Add::add)
+                }
+            }
+
+            impl<'a> Sum<&'a $name> for $name {
+                fn sum<I>(iter: I) -> Self
+                where
+                    I: Iterator<Item = &'a Self>,
+                {
+                    iter.copied().sum()
+                }
+            }
+
+            impl Product for $name {
+                fn product<I>(iter: I) -> Self
+                where
+                    I: Iterator<Item = Self>,
+                {
+                    iter.fold($name($one), Mul::mul)
+                }
+            }
+
+            impl<'a> Product<&'a $name> for $name {
+                fn product<I>(iter: I) -> Self
+                where
+                    I: Iterator<Item = &'a Self>,
+                {
+                    iter.copied().product()
+                }
+            }
+        )+
+    };
+}
+
+impl_scalar_iter!(float(0.0, 1.0), int(0, 1), uint(0, 1));
+
 /// A 32-bit boolean value.
 ///
 /// [boolean::False] is stored identically to a [uint] of `0`; [boolean::True] is stored identically
@@ -235,7 +1386,40 @@ pub enum boolean {
     False = 0,
 }
 
-unsafe impl ReprStd140 for boolean {}
+impl boolean {
+    /// Returns `"true"` or `"false"`, spelled like a GLSL boolean literal.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            boolean::True => "true",
+            boolean::False => "false",
+        }
+    }
+}
+
+impl fmt::Display for boolean {
+    /// Formats the value in lowercase like a GLSL or JSON boolean literal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::boolean;
+    ///
+    /// assert_eq!(format!("{}", boolean::True), "true");
+    /// assert_eq!(format!("{:>6}", boolean::False), " false");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl GlslType for boolean {
+    const GLSL_NAME: &'static str = "bool";
+}
+
+unsafe impl ReprStd140 for boolean {
+    const STD140_KIND: MemberKind = MemberKind::Scalar;
+    const STD140_PADDING: usize = 0;
+}
 unsafe impl Std140ArrayElement for boolean {}
 
 macro_rules! impl_from_for_boolean {
@@ -266,6 +1450,241 @@ impl From<bool> for boolean {
     }
 }
 
+impl Not for boolean {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        match self {
+            boolean::True => boolean::False,
+            boolean::False => boolean::True,
+        }
+    }
+}
+
+/// An RGBA color with 8 bits per channel, packed into a single 32-bit unsigned integer.
+///
+/// Stored identically to a [uint], with the red channel in the least significant byte and the
+/// alpha channel in the most significant byte. This matches the packing used by the GLSL
+/// `packUnorm4x8` and `unpackUnorm4x8` functions, such that a `uint` block member holding this
+/// value may be unpacked into a `vec4` in the shader.
+///
+/// # Example
+///
+/// ```
+/// use std140::packed_rgba8;
+///
+/// let color = packed_rgba8::from_rgba(255, 128, 0, 255);
+///
+/// assert_eq!(color.to_rgba(), [255, 128, 0, 255]);
+/// ```
+#[repr(C, align(4))]
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct packed_rgba8(pub u32);
+
+impl packed_rgba8 {
+    /// Packs the given red, green, blue and alpha channels.
+    pub const fn from_rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        packed_rgba8(u32::from_le_bytes([r, g, b, a]))
+    }
+
+    /// Unpacks the red, green, blue and alpha channels, in that order.
+    pub const fn to_rgba(self) -> [u8; 4] {
+        self.0.to_le_bytes()
+    }
+}
+
+impl GlslType for packed_rgba8 {
+    const GLSL_NAME: &'static str = "uint";
+}
+
+unsafe impl ReprStd140 for packed_rgba8 {
+    const STD140_KIND: MemberKind = MemberKind::Scalar;
+    const STD140_PADDING: usize = 0;
+}
+unsafe impl Std140ArrayElement for packed_rgba8 {}
+
+impl From<vec::vec4> for packed_rgba8 {
+    /// Converts a color with components in the range `0.0..=1.0` into a packed color.
+    ///
+    /// Components outside of this range are clamped, matching the behavior of `packUnorm4x8`.
+    fn from(value: vec::vec4) -> Self {
+        fn unorm8(component: f32) -> u8 {
+            (component.clamp(0.0, 1.0) * 255.0).round() as u8
+        }
+
+        packed_rgba8::from_rgba(
+            unorm8(value.0),
+            unorm8(value.1),
+            unorm8(value.2),
+            unorm8(value.3),
+        )
+    }
+}
+
+impl From<packed_rgba8> for vec::vec4 {
+    /// Converts a packed color into a color with components in the range `0.0..=1.0`.
+    fn from(value: packed_rgba8) -> Self {
+        let [r, g, b, a] = value.to_rgba();
+
+        vec::vec4(
+            r as f32 / 255.0,
+            g as f32 / 255.0,
+            b as f32 / 255.0,
+            a as f32 / 255.0,
+        )
+    }
+}
+
+/// Initializes a [mat2x2][crate::mat::mat2x2] from 4 values in row-major reading order, i.e. the
+/// 2 rows of 2 values each, as matrices are usually written down.
+///
+/// See [mat4x4!] for an example.
+#[macro_export]
+macro_rules! mat2x2 {
+    ($e0:expr, $e1:expr, $e2:expr, $e3:expr $(,)?) => {
+        $crate::mat::mat2x2::from_columns([
+            $crate::vec::vec2($e0, $e2),
+            $crate::vec::vec2($e1, $e3),
+        ])
+    };
+}
+
+/// Initializes a [mat2x3][crate::mat::mat2x3] from 6 values in row-major reading order, i.e. the
+/// 3 rows of 2 values each, as matrices are usually written down.
+///
+/// See [mat4x4!] for an example.
+#[macro_export]
+macro_rules! mat2x3 {
+    ($e0:expr, $e1:expr, $e2:expr, $e3:expr, $e4:expr, $e5:expr $(,)?) => {
+        $crate::mat::mat2x3::from_columns([
+            $crate::vec::vec3($e0, $e2, $e4),
+            $crate::vec::vec3($e1, $e3, $e5),
+        ])
+    };
+}
+
+/// Initializes a [mat2x4][crate::mat::mat2x4] from 8 values in row-major reading order, i.e. the
+/// 4 rows of 2 values each, as matrices are usually written down.
+///
+/// See [mat4x4!] for an example.
+#[macro_export]
+macro_rules! mat2x4 {
+    ($e0:expr, $e1:expr, $e2:expr, $e3:expr, $e4:expr, $e5:expr, $e6:expr, $e7:expr $(,)?) => {
+        $crate::mat::mat2x4::from_columns([
+            $crate::vec::vec4($e0, $e2, $e4, $e6),
+            $crate::vec::vec4($e1, $e3, $e5, $e7),
+        ])
+    };
+}
+
+/// Initializes a [mat3x2][crate::mat::mat3x2] from 6 values in row-major reading order, i.e. the
+/// 2 rows of 3 values each, as matrices are usually written down.
+///
+/// See [mat4x4!] for an example.
+#[macro_export]
+macro_rules! mat3x2 {
+    ($e0:expr, $e1:expr, $e2:expr, $e3:expr, $e4:expr, $e5:expr $(,)?) => {
+        $crate::mat::mat3x2::from_columns([
+            $crate::vec::vec2($e0, $e3),
+            $crate::vec::vec2($e1, $e4),
+            $crate::vec::vec2($e2, $e5),
+        ])
+    };
+}
+
+/// Initializes a [mat3x3][crate::mat::mat3x3] from 9 values in row-major reading order, i.e. the
+/// 3 rows of 3 values each, as matrices are usually written down.
+///
+/// See [mat4x4!] for an example.
+#[macro_export]
+macro_rules! mat3x3 {
+    ($e0:expr, $e1:expr, $e2:expr, $e3:expr, $e4:expr, $e5:expr, $e6:expr, $e7:expr, $e8:expr $(,)?) => {
+        $crate::mat::mat3x3::from_columns([
+            $crate::vec::vec3($e0, $e3, $e6),
+            $crate::vec::vec3($e1, $e4, $e7),
+            $crate::vec::vec3($e2, $e5, $e8),
+        ])
+    };
+}
+
+/// Initializes a [mat3x4][crate::mat::mat3x4] from 12 values in row-major reading order, i.e. the
+/// 4 rows of 3 values each, as matrices are usually written down.
+///
+/// See [mat4x4!] for an example.
+#[macro_export]
+macro_rules! mat3x4 {
+    ($e0:expr, $e1:expr, $e2:expr, $e3:expr, $e4:expr, $e5:expr, $e6:expr, $e7:expr, $e8:expr, $e9:expr, $e10:expr, $e11:expr $(,)?) => {
+        $crate::mat::mat3x4::from_columns([
+            $crate::vec::vec4($e0, $e3, $e6, $e9),
+            $crate::vec::vec4($e1, $e4, $e7, $e10),
+            $crate::vec::vec4($e2, $e5, $e8, $e11),
+        ])
+    };
+}
+
+/// Initializes a [mat4x2][crate::mat::mat4x2] from 8 values in row-major reading order, i.e. the
+/// 2 rows of 4 values each, as matrices are usually written down.
+///
+/// See [mat4x4!] for an example.
+#[macro_export]
+macro_rules! mat4x2 {
+    ($e0:expr, $e1:expr, $e2:expr, $e3:expr, $e4:expr, $e5:expr, $e6:expr, $e7:expr $(,)?) => {
+        $crate::mat::mat4x2::from_columns([
+            $crate::vec::vec2($e0, $e4),
+            $crate::vec::vec2($e1, $e5),
+            $crate::vec::vec2($e2, $e6),
+            $crate::vec::vec2($e3, $e7),
+        ])
+    };
+}
+
+/// Initializes a [mat4x3][crate::mat::mat4x3] from 12 values in row-major reading order, i.e. the
+/// 3 rows of 4 values each, as matrices are usually written down.
+///
+/// See [mat4x4!] for an example.
+#[macro_export]
+macro_rules! mat4x3 {
+    ($e0:expr, $e1:expr, $e2:expr, $e3:expr, $e4:expr, $e5:expr, $e6:expr, $e7:expr, $e8:expr, $e9:expr, $e10:expr, $e11:expr $(,)?) => {
+        $crate::mat::mat4x3::from_columns([
+            $crate::vec::vec3($e0, $e4, $e8),
+            $crate::vec::vec3($e1, $e5, $e9),
+            $crate::vec::vec3($e2, $e6, $e10),
+            $crate::vec::vec3($e3, $e7, $e11),
+        ])
+    };
+}
+
+/// Initializes a [mat4x4][crate::mat::mat4x4] from 16 values in row-major reading order, i.e. the
+/// 4 rows of 4 values each, as matrices are usually written down.
+///
+/// The values are transposed into the column-major storage of the matrix, so the first 4 values
+/// form the first row, not the first column.
+///
+/// # Example
+///
+/// ```
+/// let translation = std140::mat4x4![
+///     1.0, 0.0, 0.0, 5.0,
+///     0.0, 1.0, 0.0, 6.0,
+///     0.0, 0.0, 1.0, 7.0,
+///     0.0, 0.0, 0.0, 1.0,
+/// ];
+///
+/// assert_eq!(translation[3], std140::vec::vec4(5.0, 6.0, 7.0, 1.0));
+/// ```
+#[macro_export]
+macro_rules! mat4x4 {
+    ($e0:expr, $e1:expr, $e2:expr, $e3:expr, $e4:expr, $e5:expr, $e6:expr, $e7:expr, $e8:expr, $e9:expr, $e10:expr, $e11:expr, $e12:expr, $e13:expr, $e14:expr, $e15:expr $(,)?) => {
+        $crate::mat::mat4x4::from_columns([
+            $crate::vec::vec4($e0, $e4, $e8, $e12),
+            $crate::vec::vec4($e1, $e5, $e9, $e13),
+            $crate::vec::vec4($e2, $e6, $e10, $e14),
+            $crate::vec::vec4($e3, $e7, $e11, $e15),
+        ])
+    };
+}
+
 /// Initializes a [mat2x2][crate::mat::mat2x2].
 ///
 /// # Example
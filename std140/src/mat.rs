@@ -1,9 +1,33 @@
 use ::std::{
     fmt,
-    ops::{Deref, DerefMut},
+    ops::{Add, AddAssign, Deref, DerefMut, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign},
+    slice,
 };
 
-use crate::{array, vec, ReprStd140, Std140ArrayElement};
+use crate::{
+    array, glsl::GlslType, layout::MemberKind, vec, ByteOrder, ReprStd140, Std140ArrayElement,
+};
+
+/// Describes the dimensions of a matrix type.
+///
+/// # Example
+///
+/// ```
+/// use std140::mat::{mat4x3, Matrix};
+///
+/// assert_eq!(mat4x3::COLUMNS, 4);
+/// assert_eq!(mat4x3::ROWS, 3);
+/// ```
+pub trait Matrix {
+    /// The number of columns in the matrix.
+    const COLUMNS: usize;
+
+    /// The number of rows in the matrix, which is also the length of a column vector.
+    const ROWS: usize;
+
+    /// The vector type used to represent a column of the matrix.
+    type Column;
+}
 
 /// A matrix with 2 columns and 2 rows, represented by 2 `vec2` vectors.
 #[derive(Clone, Copy, PartialEq)]
@@ -12,17 +36,35 @@ pub struct mat2x2 {
 }
 
 impl mat2x2 {
+    /// A `mat2x2` with zeros in all positions.
+    pub const ZERO: Self = Self::zero();
+
+    /// The `mat2x2` identity matrix.
+    pub const IDENTITY: Self = Self::identity();
+
     /// Creates a new `mat2x2` with zeros in all positions.
     pub const fn zero() -> Self {
         crate::mat2x2(vec::vec2::zero(), vec::vec2::zero())
     }
 
+    /// Creates a new `mat2x2` identity matrix.
     pub const fn identity() -> Self {
         crate::mat2x2(vec::vec2(1., 0.), vec::vec2(0., 1.))
     }
 }
 
-unsafe impl ReprStd140 for mat2x2 {}
+impl GlslType for mat2x2 {
+    const GLSL_NAME: &'static str = "mat2";
+}
+
+unsafe impl ReprStd140 for mat2x2 {
+    const STD140_KIND: MemberKind = MemberKind::Matrix;
+    const STD140_PADDING: usize = 16;
+
+    fn write_std140_bytes(&self, bytes: &mut [u8], order: ByteOrder) {
+        self.columns.write_std140_bytes(bytes, order);
+    }
+}
 unsafe impl Std140ArrayElement for mat2x2 {}
 
 impl Deref for mat2x2 {
@@ -41,7 +83,8 @@ impl DerefMut for mat2x2 {
 
 impl fmt::Debug for mat2x2 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("mat2x2{:?}", &self.columns))
+        f.write_str("mat2x2")?;
+        fmt::Debug::fmt(&self.columns, f)
     }
 }
 
@@ -52,13 +95,27 @@ pub struct mat2x3 {
 }
 
 impl mat2x3 {
+    /// A `mat2x3` with zeros in all positions.
+    pub const ZERO: Self = Self::zero();
+
     /// Creates a new `mat2x3` with zeros in all positions.
     pub const fn zero() -> Self {
         crate::mat2x3(vec::vec3::zero(), vec::vec3::zero())
     }
 }
 
-unsafe impl ReprStd140 for mat2x3 {}
+impl GlslType for mat2x3 {
+    const GLSL_NAME: &'static str = "mat2x3";
+}
+
+unsafe impl ReprStd140 for mat2x3 {
+    const STD140_KIND: MemberKind = MemberKind::Matrix;
+    const STD140_PADDING: usize = 8;
+
+    fn write_std140_bytes(&self, bytes: &mut [u8], order: ByteOrder) {
+        self.columns.write_std140_bytes(bytes, order);
+    }
+}
 unsafe impl Std140ArrayElement for mat2x3 {}
 
 impl Deref for mat2x3 {
@@ -77,7 +134,8 @@ impl DerefMut for mat2x3 {
 
 impl fmt::Debug for mat2x3 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("mat2x3{:?}", &self.columns))
+        f.write_str("mat2x3")?;
+        fmt::Debug::fmt(&self.columns, f)
     }
 }
 
@@ -88,13 +146,27 @@ pub struct mat2x4 {
 }
 
 impl mat2x4 {
+    /// A `mat2x4` with zeros in all positions.
+    pub const ZERO: Self = Self::zero();
+
     /// Creates a new `mat2x4` with zeros in all positions.
     pub const fn zero() -> Self {
         crate::mat2x4(vec::vec4::zero(), vec::vec4::zero())
     }
 }
 
-unsafe impl ReprStd140 for mat2x4 {}
+impl GlslType for mat2x4 {
+    const GLSL_NAME: &'static str = "mat2x4";
+}
+
+unsafe impl ReprStd140 for mat2x4 {
+    const STD140_KIND: MemberKind = MemberKind::Matrix;
+    const STD140_PADDING: usize = 0;
+
+    fn write_std140_bytes(&self, bytes: &mut [u8], order: ByteOrder) {
+        self.columns.write_std140_bytes(bytes, order);
+    }
+}
 unsafe impl Std140ArrayElement for mat2x4 {}
 
 impl Deref for mat2x4 {
@@ -113,7 +185,8 @@ impl DerefMut for mat2x4 {
 
 impl fmt::Debug for mat2x4 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("mat2x4{:?}", &self.columns))
+        f.write_str("mat2x4")?;
+        fmt::Debug::fmt(&self.columns, f)
     }
 }
 
@@ -124,13 +197,27 @@ pub struct mat3x2 {
 }
 
 impl mat3x2 {
+    /// A `mat3x2` with zeros in all positions.
+    pub const ZERO: Self = Self::zero();
+
     /// Creates a new `mat3x2` with zeros in all positions.
     pub const fn zero() -> Self {
         crate::mat3x2(vec::vec2::zero(), vec::vec2::zero(), vec::vec2::zero())
     }
 }
 
-unsafe impl ReprStd140 for mat3x2 {}
+impl GlslType for mat3x2 {
+    const GLSL_NAME: &'static str = "mat3x2";
+}
+
+unsafe impl ReprStd140 for mat3x2 {
+    const STD140_KIND: MemberKind = MemberKind::Matrix;
+    const STD140_PADDING: usize = 24;
+
+    fn write_std140_bytes(&self, bytes: &mut [u8], order: ByteOrder) {
+        self.columns.write_std140_bytes(bytes, order);
+    }
+}
 unsafe impl Std140ArrayElement for mat3x2 {}
 
 impl Deref for mat3x2 {
@@ -149,7 +236,8 @@ impl DerefMut for mat3x2 {
 
 impl fmt::Debug for mat3x2 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("mat3x2{:?}", &self.columns))
+        f.write_str("mat3x2")?;
+        fmt::Debug::fmt(&self.columns, f)
     }
 }
 
@@ -160,11 +248,18 @@ pub struct mat3x3 {
 }
 
 impl mat3x3 {
+    /// A `mat3x3` with zeros in all positions.
+    pub const ZERO: Self = Self::zero();
+
+    /// The `mat3x3` identity matrix.
+    pub const IDENTITY: Self = Self::identity();
+
     /// Creates a new `mat3x3` with zeros in all positions.
     pub const fn zero() -> Self {
         crate::mat3x3(vec::vec3::zero(), vec::vec3::zero(), vec::vec3::zero())
     }
 
+    /// Creates a new `mat3x3` identity matrix.
     pub const fn identity() -> Self {
         crate::mat3x3(
             vec::vec3(1., 0., 0.),
@@ -174,7 +269,18 @@ impl mat3x3 {
     }
 }
 
-unsafe impl ReprStd140 for mat3x3 {}
+impl GlslType for mat3x3 {
+    const GLSL_NAME: &'static str = "mat3";
+}
+
+unsafe impl ReprStd140 for mat3x3 {
+    const STD140_KIND: MemberKind = MemberKind::Matrix;
+    const STD140_PADDING: usize = 12;
+
+    fn write_std140_bytes(&self, bytes: &mut [u8], order: ByteOrder) {
+        self.columns.write_std140_bytes(bytes, order);
+    }
+}
 unsafe impl Std140ArrayElement for mat3x3 {}
 
 impl Deref for mat3x3 {
@@ -193,7 +299,8 @@ impl DerefMut for mat3x3 {
 
 impl fmt::Debug for mat3x3 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("mat3x3{:?}", &self.columns))
+        f.write_str("mat3x3")?;
+        fmt::Debug::fmt(&self.columns, f)
     }
 }
 
@@ -204,13 +311,27 @@ pub struct mat3x4 {
 }
 
 impl mat3x4 {
+    /// A `mat3x4` with zeros in all positions.
+    pub const ZERO: Self = Self::zero();
+
     /// Creates a new `mat3x4` with zeros in all positions.
     pub const fn zero() -> Self {
         crate::mat3x4(vec::vec4::zero(), vec::vec4::zero(), vec::vec4::zero())
     }
 }
 
-unsafe impl ReprStd140 for mat3x4 {}
+impl GlslType for mat3x4 {
+    const GLSL_NAME: &'static str = "mat3x4";
+}
+
+unsafe impl ReprStd140 for mat3x4 {
+    const STD140_KIND: MemberKind = MemberKind::Matrix;
+    const STD140_PADDING: usize = 0;
+
+    fn write_std140_bytes(&self, bytes: &mut [u8], order: ByteOrder) {
+        self.columns.write_std140_bytes(bytes, order);
+    }
+}
 unsafe impl Std140ArrayElement for mat3x4 {}
 
 impl Deref for mat3x4 {
@@ -229,7 +350,8 @@ impl DerefMut for mat3x4 {
 
 impl fmt::Debug for mat3x4 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("mat3x4{:?}", &self.columns))
+        f.write_str("mat3x4")?;
+        fmt::Debug::fmt(&self.columns, f)
     }
 }
 
@@ -240,6 +362,9 @@ pub struct mat4x2 {
 }
 
 impl mat4x2 {
+    /// A `mat4x2` with zeros in all positions.
+    pub const ZERO: Self = Self::zero();
+
     /// Creates a new `mat4x2` with zeros in all positions.
     pub const fn zero() -> Self {
         crate::mat4x2(
@@ -251,7 +376,18 @@ impl mat4x2 {
     }
 }
 
-unsafe impl ReprStd140 for mat4x2 {}
+impl GlslType for mat4x2 {
+    const GLSL_NAME: &'static str = "mat4x2";
+}
+
+unsafe impl ReprStd140 for mat4x2 {
+    const STD140_KIND: MemberKind = MemberKind::Matrix;
+    const STD140_PADDING: usize = 32;
+
+    fn write_std140_bytes(&self, bytes: &mut [u8], order: ByteOrder) {
+        self.columns.write_std140_bytes(bytes, order);
+    }
+}
 unsafe impl Std140ArrayElement for mat4x2 {}
 
 impl Deref for mat4x2 {
@@ -270,7 +406,8 @@ impl DerefMut for mat4x2 {
 
 impl fmt::Debug for mat4x2 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("mat4x2{:?}", &self.columns))
+        f.write_str("mat4x2")?;
+        fmt::Debug::fmt(&self.columns, f)
     }
 }
 
@@ -281,6 +418,9 @@ pub struct mat4x3 {
 }
 
 impl mat4x3 {
+    /// A `mat4x3` with zeros in all positions.
+    pub const ZERO: Self = Self::zero();
+
     /// Creates a new `mat4x3` with zeros in all positions.
     pub const fn zero() -> Self {
         crate::mat4x3(
@@ -292,7 +432,18 @@ impl mat4x3 {
     }
 }
 
-unsafe impl ReprStd140 for mat4x3 {}
+impl GlslType for mat4x3 {
+    const GLSL_NAME: &'static str = "mat4x3";
+}
+
+unsafe impl ReprStd140 for mat4x3 {
+    const STD140_KIND: MemberKind = MemberKind::Matrix;
+    const STD140_PADDING: usize = 16;
+
+    fn write_std140_bytes(&self, bytes: &mut [u8], order: ByteOrder) {
+        self.columns.write_std140_bytes(bytes, order);
+    }
+}
 unsafe impl Std140ArrayElement for mat4x3 {}
 
 impl Deref for mat4x3 {
@@ -311,7 +462,8 @@ impl DerefMut for mat4x3 {
 
 impl fmt::Debug for mat4x3 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("mat4x3{:?}", &self.columns))
+        f.write_str("mat4x3")?;
+        fmt::Debug::fmt(&self.columns, f)
     }
 }
 
@@ -322,6 +474,12 @@ pub struct mat4x4 {
 }
 
 impl mat4x4 {
+    /// A `mat4x4` with zeros in all positions.
+    pub const ZERO: Self = Self::zero();
+
+    /// The `mat4x4` identity matrix.
+    pub const IDENTITY: Self = Self::identity();
+
     /// Creates a new `mat4x4` with zeros in all positions.
     pub const fn zero() -> Self {
         crate::mat4x4(
@@ -332,6 +490,7 @@ impl mat4x4 {
         )
     }
 
+    /// Creates a new `mat4x4` identity matrix.
     pub const fn identity() -> Self {
         crate::mat4x4(
             vec::vec4(1., 0., 0., 0.),
@@ -342,7 +501,18 @@ impl mat4x4 {
     }
 }
 
-unsafe impl ReprStd140 for mat4x4 {}
+impl GlslType for mat4x4 {
+    const GLSL_NAME: &'static str = "mat4";
+}
+
+unsafe impl ReprStd140 for mat4x4 {
+    const STD140_KIND: MemberKind = MemberKind::Matrix;
+    const STD140_PADDING: usize = 0;
+
+    fn write_std140_bytes(&self, bytes: &mut [u8], order: ByteOrder) {
+        self.columns.write_std140_bytes(bytes, order);
+    }
+}
 unsafe impl Std140ArrayElement for mat4x4 {}
 
 impl Deref for mat4x4 {
@@ -361,6 +531,609 @@ impl DerefMut for mat4x4 {
 
 impl fmt::Debug for mat4x4 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("mat4x4{:?}", &self.columns))
+        f.write_str("mat4x4")?;
+        fmt::Debug::fmt(&self.columns, f)
+    }
+}
+
+macro_rules! impl_matrix {
+    ($name:ident, $column:ty, $columns:literal, $rows:literal) => {
+        impl Matrix for $name {
+            const COLUMNS: usize = $columns;
+            const ROWS: usize = $rows;
+
+            type Column = $column;
+        }
+
+        impl Index<usize> for $name {
+            type Output = $column;
+
+            /// Returns the column vector at `index`.
+            ///
+            /// This takes precedence over indexing through [Deref], such that `matrix[i]` yields
+            /// the column vector itself rather than its array element wrapper.
+            fn index(&self, index: usize) -> &Self::Output {
+                &self.columns[index].0
+            }
+        }
+
+        impl IndexMut<usize> for $name {
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                &mut self.columns[index].0
+            }
+        }
+
+        impl IntoIterator for $name {
+            type Item = $column;
+            type IntoIter = array::IntoIter<$column, $columns>;
+
+            /// Returns an iterator over the column vectors of the matrix.
+            fn into_iter(self) -> Self::IntoIter {
+                self.columns.into_iter()
+            }
+        }
+
+        impl<'a> IntoIterator for &'a $name {
+            type Item = &'a $column;
+            type IntoIter = array::Iter<'a, $column>;
+
+            /// Returns an iterator over references to the column vectors of the matrix.
+            fn into_iter(self) -> Self::IntoIter {
+                (&self.columns).into_iter()
+            }
+        }
+
+        impl<'a> IntoIterator for &'a mut $name {
+            type Item = &'a mut $column;
+            type IntoIter = array::IterMut<'a, $column>;
+
+            /// Returns an iterator over mutable references to the column vectors of the matrix.
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_columns_mut()
+            }
+        }
+
+        impl $name {
+            /// Returns an iterator over mutable references to the column vectors of the matrix.
+            ///
+            /// The columns can't be exposed as a `&mut [vecN]` slice: each column is stored in an
+            /// [array::AlignmentedElement] aligned to 16 bytes, so for columns smaller than 16
+            /// bytes the stride between columns differs from the size of the column vector.
+            /// Instead, each column is projected out of its wrapper individually.
+            pub fn iter_columns_mut(&mut self) -> array::IterMut<'_, $column> {
+                (&mut self.columns).into_iter()
+            }
+
+            /// Returns an iterator over all elements of the matrix in column-major order, i.e. the
+            /// elements of the first column followed by those of the second column and so on.
+            ///
+            /// Unlike the std140 representation of the matrix, this does not include padding.
+            pub fn iter_flat(&self) -> impl Iterator<Item = f32> + '_ {
+                self.into_iter()
+                    .flat_map(|column| (0..$rows).map(move |row| column[row]))
+            }
+        }
+    };
+}
+
+impl_matrix!(mat2x2, vec::vec2, 2, 2);
+impl_matrix!(mat2x3, vec::vec3, 2, 3);
+impl_matrix!(mat2x4, vec::vec4, 2, 4);
+impl_matrix!(mat3x2, vec::vec2, 3, 2);
+impl_matrix!(mat3x3, vec::vec3, 3, 3);
+impl_matrix!(mat3x4, vec::vec4, 3, 4);
+impl_matrix!(mat4x2, vec::vec2, 4, 2);
+impl_matrix!(mat4x3, vec::vec3, 4, 3);
+impl_matrix!(mat4x4, vec::vec4, 4, 4);
+
+macro_rules! impl_matrix_neg {
+    ($($name:ident),+) => {
+        $(
+            impl Neg for $name {
+                type Output = Self;
+
+                fn neg(mut self) -> Self::Output {
+                    for column in self.columns.iter_mut() {
+                        column.0 = -column.0;
+                    }
+
+                    self
+                }
+            }
+        )+
+    };
+}
+
+impl_matrix_neg!(mat2x2, mat2x3, mat2x4, mat3x2, mat3x3, mat3x4, mat4x2, mat4x3, mat4x4);
+
+macro_rules! impl_matrix_op {
+    ($op:ident, $op_fn:ident, $op_assign:ident, $op_assign_fn:ident, $($name:ident),+) => {
+        $(
+            impl $op for $name {
+                type Output = Self;
+
+                fn $op_fn(mut self, rhs: Self) -> Self::Output {
+                    self.$op_assign_fn(rhs);
+                    self
+                }
+            }
+
+            impl $op_assign for $name {
+                fn $op_assign_fn(&mut self, rhs: Self) {
+                    for (column, rhs) in self.columns.iter_mut().zip(rhs.columns.iter()) {
+                        column.0.$op_assign_fn(rhs.0);
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_matrix_op!(
+    Add, add, AddAssign, add_assign, mat2x2, mat2x3, mat2x4, mat3x2, mat3x3, mat3x4, mat4x2,
+    mat4x3, mat4x4
+);
+impl_matrix_op!(
+    Sub, sub, SubAssign, sub_assign, mat2x2, mat2x3, mat2x4, mat3x2, mat3x3, mat3x4, mat4x2,
+    mat4x3, mat4x4
+);
+
+macro_rules! impl_matrix_scale {
+    ($($name:ident),+) => {
+        $(
+            impl Mul<f32> for $name {
+                type Output = Self;
+
+                fn mul(mut self, rhs: f32) -> Self::Output {
+                    self *= rhs;
+                    self
+                }
+            }
+
+            impl MulAssign<f32> for $name {
+                fn mul_assign(&mut self, rhs: f32) {
+                    for column in self.iter_columns_mut() {
+                        *column *= rhs;
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_matrix_scale!(mat2x2, mat2x3, mat2x4, mat3x2, mat3x3, mat3x4, mat4x2, mat4x3, mat4x4);
+
+macro_rules! impl_square_matrix_mul {
+    ($($name:ident: $column:ident),+) => {
+        $(
+            impl Mul<vec::$column> for $name {
+                type Output = vec::$column;
+
+                /// Transforms the column vector `rhs` by the matrix.
+                fn mul(self, rhs: vec::$column) -> Self::Output {
+                    self.columns
+                        .iter()
+                        .enumerate()
+                        .fold(vec::$column::zero(), |sum, (i, column)| sum + column.0 * rhs[i])
+                }
+            }
+
+            impl Mul for $name {
+                type Output = Self;
+
+                /// Returns the matrix product `self * rhs`, which applies `rhs` first when
+                /// transforming a vector.
+                fn mul(self, mut rhs: Self) -> Self::Output {
+                    for column in rhs.iter_columns_mut() {
+                        *column = self * *column;
+                    }
+
+                    rhs
+                }
+            }
+
+            impl MulAssign for $name {
+                fn mul_assign(&mut self, rhs: Self) {
+                    *self = *self * rhs;
+                }
+            }
+        )+
+    };
+}
+
+impl_square_matrix_mul!(mat2x2: vec2, mat3x3: vec3, mat4x4: vec4);
+
+macro_rules! impl_square_matrix_diagonal {
+    ($($name:ident),+) => {
+        $(
+            impl $name {
+                /// Returns the sum of the elements on the main diagonal.
+                pub fn trace(&self) -> f32 {
+                    self.columns.iter().enumerate().map(|(i, column)| column.0[i]).sum()
+                }
+
+                /// Returns `true` if every element differs from the identity matrix by at most
+                /// `epsilon`.
+                pub fn is_identity(&self, epsilon: f32) -> bool {
+                    self.columns.iter().enumerate().all(|(i, column)| {
+                        (0..<Self as Matrix>::ROWS).all(|j| {
+                            let expected = if i == j { 1.0 } else { 0.0 };
+
+                            (column.0[j] - expected).abs() <= epsilon
+                        })
+                    })
+                }
+            }
+        )+
+    };
+}
+
+impl_square_matrix_diagonal!(mat2x2, mat3x3, mat4x4);
+
+impl mat3x3 {
+    /// Creates a rotation matrix from Euler angles in radians, rotating about the x axis by `x`
+    /// first, then about the y axis by `y` and finally about the z axis by `z`.
+    ///
+    /// The axes are fixed (extrinsic rotations), so the result equals `Rz * Ry * Rx`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::{mat::mat3x3, vec::vec3};
+    ///
+    /// let quarter_turn_z = mat3x3::from_euler_xyz(0.0, 0.0, std::f32::consts::FRAC_PI_2);
+    ///
+    /// assert!((quarter_turn_z * vec3(1.0, 0.0, 0.0) - vec3(0.0, 1.0, 0.0)).abs().max_element() < 1e-6);
+    /// ```
+    pub fn from_euler_xyz(x: f32, y: f32, z: f32) -> Self {
+        let (sx, cx) = x.sin_cos();
+        let (sy, cy) = y.sin_cos();
+        let (sz, cz) = z.sin_cos();
+
+        crate::mat3x3(
+            vec::vec3(cy * cz, cy * sz, -sy),
+            vec::vec3(sx * sy * cz - cx * sz, sx * sy * sz + cx * cz, sx * cy),
+            vec::vec3(cx * sy * cz + sx * sz, cx * sy * sz - sx * cz, cx * cy),
+        )
+    }
+
+    /// Extracts the Euler angles `(x, y, z)` in radians of a rotation matrix, such that
+    /// [from_euler_xyz][Self::from_euler_xyz] recreates the rotation.
+    ///
+    /// `x` and `z` are in `-PI..=PI` and `y` is in `-PI / 2..=PI / 2`. When `y` is (close to)
+    /// `±PI / 2`, rotations about x and z turn about the same axis (gimbal lock) and only their
+    /// combination is determined; `z` is then reported as `0` and `x` holds the whole rotation.
+    pub fn to_euler_xyz(&self) -> (f32, f32, f32) {
+        let [c0, c1, c2] = [self[0], self[1], self[2]];
+        let y = (-c0.2).clamp(-1.0, 1.0).asin();
+
+        if c0.0.hypot(c0.1) > 1e-6 {
+            (c1.2.atan2(c2.2), y, c0.1.atan2(c0.0))
+        } else if c0.2 < 0.0 {
+            (c1.0.atan2(c2.0), y, 0.0)
+        } else {
+            ((-c1.0).atan2(-c2.0), y, 0.0)
+        }
+    }
+}
+
+impl mat4x4 {
+    /// Creates a matrix translating points by `translation`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::{mat::mat4x4, vec::{vec3, vec4}};
+    ///
+    /// let m = mat4x4::from_translation(vec3(1.0, 2.0, 3.0));
+    ///
+    /// assert_eq!(m * vec4(1.0, 1.0, 1.0, 1.0), vec4(2.0, 3.0, 4.0, 1.0));
+    /// ```
+    pub fn from_translation(translation: vec::vec3) -> Self {
+        let mut matrix = Self::identity();
+        matrix[3] = translation.extend(1.0);
+
+        matrix
+    }
+
+    /// Creates a matrix that scales by `scale`, then rotates by `rotation` and finally translates
+    /// by `translation`, i.e. `T * R * S`, as is common for the nodes of a scene graph.
+    ///
+    /// With the `mint` feature, [mat3x3::from_mint_quaternion] creates the rotation from a
+    /// quaternion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::{mat::{mat3x3, mat4x4}, vec::{vec3, vec4}};
+    ///
+    /// let quarter_turn_z = mat3x3::from_euler_xyz(0.0, 0.0, std::f32::consts::FRAC_PI_2);
+    /// let m = mat4x4::from_scale_rotation_translation(
+    ///     vec3(2.0, 2.0, 2.0),
+    ///     quarter_turn_z,
+    ///     vec3(0.0, 0.0, 5.0),
+    /// );
+    ///
+    /// let p = m * vec4(1.0, 0.0, 0.0, 1.0);
+    /// assert!((p - vec4(0.0, 2.0, 5.0, 1.0)).abs().max_element() < 1e-6);
+    /// ```
+    pub fn from_scale_rotation_translation(
+        scale: vec::vec3,
+        rotation: mat3x3,
+        translation: vec::vec3,
+    ) -> Self {
+        let [x, y, z] = rotation.into_columns();
+
+        crate::mat4x4(
+            (x * scale.0).extend(0.0),
+            (y * scale.1).extend(0.0),
+            (z * scale.2).extend(0.0),
+            translation.extend(1.0),
+        )
     }
 }
+
+macro_rules! impl_matrix_const_eq {
+    ($($name:ident),+) => {
+        $(
+            impl $name {
+                /// Compares two matrices column by column in a `const` context, where [PartialEq]
+                /// is not usable, e.g. to check a constant matrix in a `const` assertion.
+                ///
+                /// The elements are compared bitwise, so unlike `==` this considers `NaN` equal to
+                /// a `NaN` with the same bits, and `0.0` different from `-0.0`.
+                ///
+                /// # Example
+                ///
+                /// ```
+                #[doc = concat!("use std140::mat::", stringify!($name), ";")]
+                ///
+                #[doc = concat!("const _: () = assert!(", stringify!($name), "::ZERO.const_eq(&", stringify!($name), "::zero()));")]
+                /// ```
+                pub const fn const_eq(&self, other: &Self) -> bool {
+                    let (columns, other_columns) = (self.columns.elements(), other.columns.elements());
+                    let mut index = 0;
+
+                    while index < columns.len() {
+                        if !columns[index].0.const_eq(&other_columns[index].0) {
+                            return false;
+                        }
+
+                        index += 1;
+                    }
+
+                    true
+                }
+            }
+        )+
+    };
+}
+
+impl_matrix_const_eq!(mat2x2, mat2x3, mat2x4, mat3x2, mat3x3, mat3x4, mat4x2, mat4x3, mat4x4);
+
+macro_rules! impl_matrix_columns {
+    ($($name:ident: [$column:ident; $len:literal]),+) => {
+        $(
+            impl $name {
+                /// Creates a matrix from its column vectors.
+                pub fn from_columns(columns: [vec::$column; $len]) -> Self {
+                    Self {
+                        columns: array::array::new(columns.map(array::AlignmentedElement::new)),
+                    }
+                }
+
+                /// Returns the column vectors of the matrix, the inverse of `from_columns`.
+                pub fn into_columns(self) -> [vec::$column; $len] {
+                    ::std::array::from_fn(|i| self.columns[i].0)
+                }
+            }
+        )+
+    };
+}
+
+impl_matrix_columns!(
+    mat2x2: [vec2; 2],
+    mat2x3: [vec3; 2],
+    mat2x4: [vec4; 2],
+    mat3x2: [vec2; 3],
+    mat3x3: [vec3; 3],
+    mat3x4: [vec4; 3],
+    mat4x2: [vec2; 4],
+    mat4x3: [vec3; 4],
+    mat4x4: [vec4; 4]
+);
+
+macro_rules! impl_matrix_rows {
+    ($($name:ident: [[f32; $columns:literal]; $rows:literal]),+) => {
+        $(
+            impl $name {
+                /// Creates a matrix from its rows, each holding one value per column, i.e. in the
+                /// order matrices are usually written down.
+                pub fn from_rows(rows: [[f32; $columns]; $rows]) -> Self {
+                    let mut matrix = Self::zero();
+
+                    for (row, values) in rows.iter().enumerate() {
+                        for (column, value) in values.iter().enumerate() {
+                            matrix[column][row] = *value;
+                        }
+                    }
+
+                    matrix
+                }
+            }
+        )+
+    };
+}
+
+impl_matrix_rows!(
+    mat2x2: [[f32; 2]; 2],
+    mat2x3: [[f32; 2]; 3],
+    mat2x4: [[f32; 2]; 4],
+    mat3x2: [[f32; 3]; 2],
+    mat3x3: [[f32; 3]; 3],
+    mat3x4: [[f32; 3]; 4],
+    mat4x2: [[f32; 4]; 2],
+    mat4x3: [[f32; 4]; 3],
+    mat4x4: [[f32; 4]; 4]
+);
+
+macro_rules! impl_matrix_column_mutators {
+    ($($name:ident),+) => {
+        $(
+            impl $name {
+                /// Swaps the columns at indices `a` and `b`.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `a` or `b` is out of bounds.
+                pub fn swap_columns(&mut self, a: usize, b: usize) {
+                    self.columns.swap(a, b);
+                }
+
+                /// Negates every element of the column at index `i`, e.g. to flip the handedness
+                /// of a coordinate system.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `i` is out of bounds.
+                pub fn negate_column(&mut self, i: usize) {
+                    let column = &mut self.columns[i].0;
+                    *column = -*column;
+                }
+            }
+        )+
+    };
+}
+
+impl_matrix_column_mutators!(
+    mat2x2, mat2x3, mat2x4, mat3x2, mat3x3, mat3x4, mat4x2, mat4x3, mat4x4
+);
+
+macro_rules! impl_matrix_lerp {
+    ($($name:ident),+) => {
+        $(
+            impl $name {
+                /// Linearly interpolates between `self` and `other` by `t`, column by column.
+                ///
+                /// This mirrors GLSL's `mix`: `t` is not clamped, so values outside `[0, 1]`
+                /// extrapolate beyond `self` and `other`.
+                pub fn lerp(mut self, other: Self, t: f32) -> Self {
+                    for (column, other) in self.columns.iter_mut().zip(other.columns.iter()) {
+                        column.0 = column.0.lerp(other.0, t);
+                    }
+
+                    self
+                }
+            }
+        )+
+    };
+}
+
+impl_matrix_lerp!(mat2x2, mat2x3, mat2x4, mat3x2, mat3x3, mat3x4, mat4x2, mat4x3, mat4x4);
+
+macro_rules! impl_matrix_float_checks {
+    ($($name:ident),+) => {
+        $(
+            impl $name {
+                /// Returns `true` if every element is neither infinite nor NaN.
+                ///
+                /// Useful to validate a computed matrix before uploading it, as non-finite values
+                /// corrupt rendering.
+                pub fn is_finite(&self) -> bool {
+                    self.into_iter().all(|column| column.is_finite())
+                }
+
+                /// Returns `true` if any element is NaN.
+                pub fn is_nan(&self) -> bool {
+                    self.into_iter().any(|column| column.is_nan())
+                }
+
+                /// Returns the elementwise absolute value.
+                pub fn abs(&self) -> Self {
+                    let mut matrix = *self;
+
+                    for column in matrix.iter_columns_mut() {
+                        *column = column.abs();
+                    }
+
+                    matrix
+                }
+            }
+        )+
+    };
+}
+
+impl_matrix_float_checks!(mat2x2, mat2x3, mat2x4, mat3x2, mat3x3, mat3x4, mat4x2, mat4x3, mat4x4);
+
+macro_rules! impl_matrix_to_flat_array {
+    ($($name:ident: $len:literal),+) => {
+        $(
+            impl $name {
+                #[doc = concat!("Returns the ", stringify!($len), " elements of the matrix in column-major order, without padding.")]
+                ///
+                /// Use this to hand the matrix to an API expecting a tightly packed float array.
+                /// Unlike `as_slice`, which is only available for matrices with 4 rows, this copies
+                /// the elements, as the columns of matrices with fewer rows are padded to 16 bytes.
+                pub fn to_flat_array(&self) -> [f32; $len] {
+                    let mut elements = [0.0; $len];
+
+                    for (element, value) in elements.iter_mut().zip(self.iter_flat()) {
+                        *element = value;
+                    }
+
+                    elements
+                }
+            }
+        )+
+    };
+}
+
+impl_matrix_to_flat_array!(
+    mat2x2: 4,
+    mat2x3: 6,
+    mat2x4: 8,
+    mat3x2: 6,
+    mat3x3: 9,
+    mat3x4: 12,
+    mat4x2: 8,
+    mat4x3: 12,
+    mat4x4: 16
+);
+
+macro_rules! impl_matrix_as_slice {
+    ($($name:ident: $len:literal),+) => {
+        $(
+            impl $name {
+                #[doc = concat!("Returns a view on the ", stringify!($len), " elements of the matrix in column-major order.")]
+                ///
+                /// The columns of a matrix with 4 rows are `vec4`s, which are stored without any
+                /// padding, so the matrix can be borrowed as a tightly packed float slice. Matrices
+                /// with fewer rows pad each column to 16 bytes and only offer the copying
+                /// `to_flat_array` instead.
+                pub fn as_slice(&self) -> &[f32] {
+                    // SAFETY: the columns are stored contiguously as 16 byte aligned `vec4`s without
+                    // padding, each made up of 4 `f32`s, so they form a valid `[f32; $len]`. The
+                    // returned slice borrows from `self`.
+                    unsafe { slice::from_raw_parts(self.columns.as_ptr() as *const f32, $len) }
+                }
+
+                /// Returns a mutable view on the elements of the matrix in column-major order, see
+                #[doc = concat!("[", stringify!($name), "::as_slice].")]
+                pub fn as_mut_slice(&mut self) -> &mut [f32] {
+                    // SAFETY: see `as_slice`; the returned slice mutably borrows from `self`.
+                    unsafe { slice::from_raw_parts_mut(self.columns.as_mut_ptr() as *mut f32, $len) }
+                }
+            }
+
+            /// Views the elements of the matrix in column-major order, see
+            #[doc = concat!("[", stringify!($name), "::as_slice].")]
+            impl AsRef<[f32]> for $name {
+                fn as_ref(&self) -> &[f32] {
+                    self.as_slice()
+                }
+            }
+        )+
+    };
+}
+
+impl_matrix_as_slice!(mat2x4: 8, mat3x4: 12, mat4x4: 16);
@@ -0,0 +1,78 @@
+use crate::{
+    mat::{mat3x3, mat4x4},
+    vec::{vec3, vec4},
+};
+
+/// Returns the columns of the rotation matrix described by the unit quaternion `q`.
+fn rotation_columns(q: ::mint::Quaternion<f32>) -> [vec3; 3] {
+    let ::mint::Quaternion {
+        v: ::mint::Vector3 { x, y, z },
+        s: w,
+    } = q;
+
+    [
+        vec3(
+            1.0 - 2.0 * (y * y + z * z),
+            2.0 * (x * y + w * z),
+            2.0 * (x * z - w * y),
+        ),
+        vec3(
+            2.0 * (x * y - w * z),
+            1.0 - 2.0 * (x * x + z * z),
+            2.0 * (y * z + w * x),
+        ),
+        vec3(
+            2.0 * (x * z + w * y),
+            2.0 * (y * z - w * x),
+            1.0 - 2.0 * (x * x + y * y),
+        ),
+    ]
+}
+
+impl mat3x3 {
+    /// Creates a rotation matrix from a [mint::Quaternion].
+    ///
+    /// The quaternion is expected to be normalized; a non-unit quaternion also scales.
+    ///
+    /// Only available with the `mint` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::{mat::mat3x3, vec::vec3};
+    ///
+    /// let half_sqrt = std::f32::consts::FRAC_1_SQRT_2;
+    /// let quarter_turn_z = mint::Quaternion {
+    ///     v: mint::Vector3 { x: 0.0, y: 0.0, z: half_sqrt },
+    ///     s: half_sqrt,
+    /// };
+    ///
+    /// let rotation = mat3x3::from_mint_quaternion(quarter_turn_z);
+    ///
+    /// assert!((rotation[0].1 - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn from_mint_quaternion(q: ::mint::Quaternion<f32>) -> Self {
+        let [x, y, z] = rotation_columns(q);
+
+        crate::mat3x3(x, y, z)
+    }
+}
+
+impl mat4x4 {
+    /// Creates a rotation matrix from a [mint::Quaternion], with no
+    /// translation.
+    ///
+    /// The quaternion is expected to be normalized; a non-unit quaternion also scales.
+    ///
+    /// Only available with the `mint` feature.
+    pub fn from_mint_quaternion(q: ::mint::Quaternion<f32>) -> Self {
+        let [x, y, z] = rotation_columns(q);
+
+        crate::mat4x4(
+            vec4(x.0, x.1, x.2, 0.0),
+            vec4(y.0, y.1, y.2, 0.0),
+            vec4(z.0, z.1, z.2, 0.0),
+            vec4(0.0, 0.0, 0.0, 1.0),
+        )
+    }
+}
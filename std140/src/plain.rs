@@ -0,0 +1,172 @@
+//! Conversions between std140 types and plain Rust types holding the same values.
+
+use crate::{
+    array::{array, AlignmentedElement},
+    boolean, float, int, mat, packed_rgba8, uint, vec, Std140ArrayElement,
+};
+
+/// A type with a plain Rust counterpart, e.g. `[f32; 3]` for a [vec3][vec::vec3].
+///
+/// Implemented for the scalar, vector and matrix types, std140 arrays of such types and structs
+/// marked with [`#[repr_std140(mirror(...))]`][crate::repr_std140], whose counterpart is the
+/// mirror struct. Vectors map to arrays of their components and matrices to arrays of their
+/// columns; booleans map to `bool` and `packed_rgba8` to the `u32` holding its packed channels.
+///
+/// # Example
+///
+/// ```
+/// use std140::{mat::mat2x2, plain::PlainRepr, vec::vec2};
+///
+/// let columns = std140::mat2x2(vec2(1.0, 0.0), vec2(0.0, 2.0)).into_plain();
+///
+/// assert_eq!(columns, [[1.0, 0.0], [0.0, 2.0]]);
+/// assert_eq!(mat2x2::from_plain(columns), std140::mat2x2(vec2(1.0, 0.0), vec2(0.0, 2.0)));
+/// ```
+pub trait PlainRepr {
+    /// The plain Rust type holding the same values.
+    type Plain;
+
+    /// Converts the value into its plain counterpart.
+    fn into_plain(self) -> Self::Plain;
+
+    /// Converts a plain value back into the std140 type.
+    fn from_plain(plain: Self::Plain) -> Self;
+}
+
+macro_rules! impl_scalar_plain {
+    ($($name:ident: $plain:ty),+) => {
+        $(
+            impl PlainRepr for $name {
+                type Plain = $plain;
+
+                fn into_plain(self) -> Self::Plain {
+                    self.0
+                }
+
+                fn from_plain(plain: Self::Plain) -> Self {
+                    $name(plain)
+                }
+            }
+        )+
+    };
+}
+
+impl_scalar_plain!(float: f32, int: i32, uint: u32, packed_rgba8: u32);
+
+impl PlainRepr for boolean {
+    type Plain = bool;
+
+    fn into_plain(self) -> Self::Plain {
+        self == boolean::True
+    }
+
+    fn from_plain(plain: Self::Plain) -> Self {
+        boolean::from(plain)
+    }
+}
+
+macro_rules! impl_vec_plain {
+    ($($name:ident: [$scalar:ty; $len:literal] => ($($index:tt),+)),+) => {
+        $(
+            impl PlainRepr for vec::$name {
+                type Plain = [$scalar; $len];
+
+                fn into_plain(self) -> Self::Plain {
+                    [$(self.$index),+]
+                }
+
+                fn from_plain(plain: Self::Plain) -> Self {
+                    vec::$name($(plain[$index]),+)
+                }
+            }
+        )+
+    };
+}
+
+impl_vec_plain!(
+    vec2: [f32; 2] => (0, 1),
+    vec3: [f32; 3] => (0, 1, 2),
+    vec4: [f32; 4] => (0, 1, 2, 3),
+    ivec2: [i32; 2] => (0, 1),
+    ivec3: [i32; 3] => (0, 1, 2),
+    ivec4: [i32; 4] => (0, 1, 2, 3),
+    uvec2: [u32; 2] => (0, 1),
+    uvec3: [u32; 3] => (0, 1, 2),
+    uvec4: [u32; 4] => (0, 1, 2, 3)
+);
+
+macro_rules! impl_bvec_plain {
+    ($($name:ident: $len:literal => ($($index:tt),+)),+) => {
+        $(
+            impl PlainRepr for vec::$name {
+                type Plain = [bool; $len];
+
+                fn into_plain(self) -> Self::Plain {
+                    [$(self.$index.into_plain()),+]
+                }
+
+                fn from_plain(plain: Self::Plain) -> Self {
+                    vec::$name($(boolean::from_plain(plain[$index])),+)
+                }
+            }
+        )+
+    };
+}
+
+impl_bvec_plain!(
+    bvec2: 2 => (0, 1),
+    bvec3: 3 => (0, 1, 2),
+    bvec4: 4 => (0, 1, 2, 3)
+);
+
+macro_rules! impl_matrix_plain {
+    ($($name:ident: [$column:ident; $len:literal]),+) => {
+        $(
+            impl PlainRepr for mat::$name {
+                type Plain = [<vec::$column as PlainRepr>::Plain; $len];
+
+                fn into_plain(self) -> Self::Plain {
+                    self.into_columns().map(PlainRepr::into_plain)
+                }
+
+                fn from_plain(plain: Self::Plain) -> Self {
+                    Self::from_columns(plain.map(PlainRepr::from_plain))
+                }
+            }
+        )+
+    };
+}
+
+impl_matrix_plain!(
+    mat2x2: [vec2; 2],
+    mat2x3: [vec3; 2],
+    mat2x4: [vec4; 2],
+    mat3x2: [vec2; 3],
+    mat3x3: [vec3; 3],
+    mat3x4: [vec4; 3],
+    mat4x2: [vec2; 4],
+    mat4x3: [vec3; 4],
+    mat4x4: [vec4; 4]
+);
+
+impl<T, const LEN: usize> PlainRepr for array<T, { LEN }>
+where
+    T: Std140ArrayElement + PlainRepr,
+{
+    type Plain = [T::Plain; LEN];
+
+    fn into_plain(self) -> Self::Plain {
+        let mut elements = self.into_iter();
+
+        ::std::array::from_fn(|_| {
+            elements
+                .next()
+                .expect("an array has LEN elements")
+                .into_plain()
+        })
+    }
+
+    fn from_plain(plain: Self::Plain) -> Self {
+        array::new(plain.map(|element| AlignmentedElement::new(T::from_plain(element))))
+    }
+}
@@ -0,0 +1,267 @@
+//! [serde](https://docs.rs/serde) support, available with the `serde` feature.
+//!
+//! Scalars serialize as their primitive value, [boolean]s as `bool`, vectors as a sequence of
+//! their components, and matrices as a sequence of their column vectors (column-major order, as
+//! laid out in memory). Unbounded arrays serialize as a sequence of their unwrapped elements.
+//! Wrap a value in [Flat] to serialize vectors and matrices as a single flat
+//! sequence of numbers instead.
+//!
+//! [boolean]: crate::boolean
+
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    array::{array, AlignmentedElement},
+    boolean,
+    mat::{mat2x2, mat2x3, mat2x4, mat3x2, mat3x3, mat3x4, mat4x2, mat4x3, mat4x4},
+    plain::PlainRepr,
+    unbounded_array::unbounded_array,
+    vec, Std140ArrayElement,
+};
+
+impl Serialize for boolean {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(*self == boolean::True)
+    }
+}
+
+impl<'de> Deserialize<'de> for boolean {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        bool::deserialize(deserializer).map(boolean::from)
+    }
+}
+
+impl<T> Serialize for unbounded_array<T>
+where
+    T: Std140ArrayElement + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter().map(|element| &element.0))
+    }
+}
+
+impl<'de, T> Deserialize<'de> for unbounded_array<T>
+where
+    T: Std140ArrayElement + Deserialize<'de>,
+{
+    /// Deserializes a sequence of any length, wrapping each element.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let elements = Vec::<T>::deserialize(deserializer)?;
+
+        Ok(elements.into_iter().map(AlignmentedElement::new).collect())
+    }
+}
+
+macro_rules! impl_matrix_serde {
+    ($name:ident, $column:ty, $columns:literal, $rows:literal, $len:literal) => {
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let columns: [$column; $columns] = ::std::array::from_fn(|index| self[index]);
+
+                columns.serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let columns = <[$column; $columns]>::deserialize(deserializer)?;
+
+                Ok($name {
+                    columns: array::new(columns.map(AlignmentedElement::new)),
+                })
+            }
+        }
+
+        impl row_major::RowMajor for $name {
+            type Rows = [[f32; $columns]; $rows];
+
+            fn to_rows(&self) -> Self::Rows {
+                ::std::array::from_fn(|row| ::std::array::from_fn(|column| self[column][row]))
+            }
+
+            fn from_rows(rows: Self::Rows) -> Self {
+                $name::from_rows(rows)
+            }
+        }
+
+        impl Flatten for $name {
+            type Flat = [f32; $len];
+
+            fn to_flat(&self) -> Self::Flat {
+                self.to_flat_array()
+            }
+
+            fn from_flat(flat: Self::Flat) -> Self {
+                let mut matrix = $name::zero();
+
+                for (index, value) in flat.iter().enumerate() {
+                    matrix[index / $rows][index % $rows] = *value;
+                }
+
+                matrix
+            }
+        }
+    };
+}
+
+impl_matrix_serde!(mat2x2, vec::vec2, 2, 2, 4);
+impl_matrix_serde!(mat2x3, vec::vec3, 2, 3, 6);
+impl_matrix_serde!(mat2x4, vec::vec4, 2, 4, 8);
+impl_matrix_serde!(mat3x2, vec::vec2, 3, 2, 6);
+impl_matrix_serde!(mat3x3, vec::vec3, 3, 3, 9);
+impl_matrix_serde!(mat3x4, vec::vec4, 3, 4, 12);
+impl_matrix_serde!(mat4x2, vec::vec2, 4, 2, 8);
+impl_matrix_serde!(mat4x3, vec::vec3, 4, 3, 12);
+impl_matrix_serde!(mat4x4, vec::vec4, 4, 4, 16);
+
+macro_rules! impl_vec_flatten {
+    ($($name:ident),+) => {
+        $(
+            impl Flatten for vec::$name {
+                type Flat = <vec::$name as PlainRepr>::Plain;
+
+                fn to_flat(&self) -> Self::Flat {
+                    self.into_plain()
+                }
+
+                fn from_flat(flat: Self::Flat) -> Self {
+                    vec::$name::from_plain(flat)
+                }
+            }
+        )+
+    };
+}
+
+impl_vec_flatten!(vec2, vec3, vec4, ivec2, ivec3, ivec4, uvec2, uvec3, uvec4);
+
+/// Conversion between a vector or matrix and its flat sequence of numbers; implemented for the
+/// numeric vector types and all matrix types.
+pub trait Flatten: Sized {
+    /// The components of a vector, or the elements of a matrix in column-major order.
+    type Flat: Serialize + for<'de> Deserialize<'de>;
+
+    /// Returns the flat sequence of numbers.
+    fn to_flat(&self) -> Self::Flat;
+
+    /// Creates a value from its flat sequence of numbers.
+    fn from_flat(flat: Self::Flat) -> Self;
+}
+
+/// Serializes and deserializes a vector or matrix as a flat sequence of numbers.
+///
+/// Matrices are flattened in column-major order, so a [mat4x4] serializes as 16 numbers laid out
+/// like an OpenGL style `float[16]`. Vectors serialize the same way with or without the wrapper.
+///
+/// # Example
+///
+/// ```
+/// use std140::{mat::mat2x2, serde::Flat, vec::vec2};
+///
+/// let matrix = std140::mat2x2(vec2(1.0, 2.0), vec2(3.0, 4.0));
+///
+/// assert_eq!(serde_json::to_string(&Flat(matrix)).unwrap(), "[1.0,2.0,3.0,4.0]");
+///
+/// let Flat(loaded) = serde_json::from_str::<Flat<mat2x2>>("[1, 2, 3, 4]").unwrap();
+/// assert_eq!(loaded, matrix);
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Flat<T>(pub T);
+
+impl<T> Serialize for Flat<T>
+where
+    T: Flatten,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.to_flat().serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Flat<T>
+where
+    T: Flatten,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::Flat::deserialize(deserializer).map(|flat| Flat(T::from_flat(flat)))
+    }
+}
+
+/// Serializes and deserializes matrices in row-major order, for use with `#[serde(with)]`.
+///
+/// A matrix is written as a sequence of its rows, each a sequence of numbers, which matches how
+/// matrices are usually written by hand in configuration files. The matrix itself is still stored
+/// column-major.
+///
+/// # Example
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use std140::mat::mat2x2;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(with = "std140::serde::row_major")]
+///     transform: mat2x2,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{ "transform": [[1, 2], [3, 4]] }"#).unwrap();
+///
+/// // The first column holds the first element of each row.
+/// assert_eq!(config.transform[0], std140::vec::vec2(1.0, 3.0));
+/// ```
+pub mod row_major {
+    use super::*;
+
+    /// Conversion between a matrix and its rows; implemented for all matrix types.
+    pub trait RowMajor: Sized {
+        /// The rows of the matrix, each holding one element per column.
+        type Rows: Serialize + for<'de> Deserialize<'de>;
+
+        /// Returns the rows of the matrix.
+        fn to_rows(&self) -> Self::Rows;
+
+        /// Creates a matrix from its rows.
+        fn from_rows(rows: Self::Rows) -> Self;
+    }
+
+    /// Serializes `matrix` as a sequence of rows.
+    pub fn serialize<M, S>(matrix: &M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        M: RowMajor,
+        S: Serializer,
+    {
+        matrix.to_rows().serialize(serializer)
+    }
+
+    /// Deserializes a matrix from a sequence of rows.
+    pub fn deserialize<'de, M, D>(deserializer: D) -> Result<M, D::Error>
+    where
+        M: RowMajor,
+        D: Deserializer<'de>,
+    {
+        M::Rows::deserialize(deserializer).map(M::from_rows)
+    }
+}
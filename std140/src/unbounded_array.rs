@@ -1,13 +1,23 @@
 use ::std::{
+    cmp::Ordering,
     fmt,
-    ops::{Deref, DerefMut},
+    ops::{Deref, DerefMut, Index, IndexMut},
+    slice::SliceIndex,
 };
 
-use crate::{array::AlignmentedElement, ReprStd140, Std140ArrayElement};
+use crate::{
+    array::{array, AlignmentedElement},
+    glsl::{GlslType, TypeName},
+    layout::MemberKind,
+    ByteOrder, ReprStd140, Std140ArrayElement,
+};
 
 /// Represents an std140 compatible unbounded array.
 ///
 /// All elements in an std140 array are aligned to at least 16 bytes.
+///
+/// Like an [array][struct@array], an unbounded array dereferences to its [AlignmentedElement]s, so slice methods
+/// such as `split_at` yield `&[AlignmentedElement<T>]`; see [the array docs][struct@array#slices].
 #[derive(Clone)]
 #[repr(C, align(16))]
 pub struct unbounded_array<T>(Vec<AlignmentedElement<T>>)
@@ -22,6 +32,244 @@ where
     pub const fn new(inner: Vec<AlignmentedElement<T>>) -> Self {
         Self(inner)
     }
+
+    /// Creates an array by copying each element of `slice` into its own 16 byte aligned slot.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::{unbounded_array::unbounded_array, vec::vec2};
+    ///
+    /// let positions = [vec2(0.0, 0.0), vec2(1.0, 0.0), vec2(1.0, 1.0)];
+    /// let array = unbounded_array::from_slice(&positions);
+    ///
+    /// assert_eq!(array.len(), 3);
+    /// assert_eq!(array.last(), Some(&vec2(1.0, 1.0)));
+    /// ```
+    pub fn from_slice(slice: &[T]) -> Self
+    where
+        T: Copy,
+    {
+        slice.iter().copied().map(AlignmentedElement).collect()
+    }
+
+    /// Creates an array of `len` elements, where each element is the result of calling `f` with
+    /// the element's index.
+    ///
+    /// This is the dynamically sized counterpart of [array::from_fn][crate::array::array::from_fn].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::{mat::mat4x4, unbounded_array::unbounded_array};
+    ///
+    /// let bones = unbounded_array::from_fn(3, |i| mat4x4::identity() * (i + 1) as f32);
+    ///
+    /// assert_eq!(bones.len(), 3);
+    /// assert_eq!(bones.last(), Some(&(mat4x4::identity() * 3.0)));
+    /// ```
+    pub fn from_fn<F>(len: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        (0..len).map(|i| AlignmentedElement(f(i))).collect()
+    }
+
+    /// Creates an array of `len` elements like [from_fn][Self::from_fn], but calls `f` for the
+    /// elements in parallel on the [rayon] thread pool.
+    ///
+    /// Only available with the `rayon` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::{unbounded_array::unbounded_array, vec::vec4};
+    ///
+    /// let offsets = unbounded_array::par_from_fn(100_000, |i| vec4(i as f32, 0.0, 0.0, 1.0));
+    ///
+    /// assert_eq!(offsets.len(), 100_000);
+    /// assert_eq!(offsets.last(), Some(&vec4(99_999.0, 0.0, 0.0, 1.0)));
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn par_from_fn<F>(len: usize, f: F) -> Self
+    where
+        T: Send,
+        F: Fn(usize) -> T + Sync,
+    {
+        use ::rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+        Self(
+            (0..len)
+                .into_par_iter()
+                .map(|i| AlignmentedElement(f(i)))
+                .collect(),
+        )
+    }
+
+    /// Returns an iterator over the unwrapped elements in `range`, e.g. to update a page of a
+    /// storage buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds, like slice indexing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::{uint, unbounded_array::unbounded_array};
+    ///
+    /// let ids = unbounded_array::from_fn(20, |i| uint(i as u32));
+    ///
+    /// assert!(ids.sub(10..13).eq(&[uint(10), uint(11), uint(12)]));
+    /// ```
+    pub fn sub<R>(&self, range: R) -> impl Iterator<Item = &T>
+    where
+        R: SliceIndex<[AlignmentedElement<T>], Output = [AlignmentedElement<T>]>,
+    {
+        self.0[range].iter().map(|element| &element.0)
+    }
+
+    /// Returns the first element of the array, or `None` if it is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let values = std140::unbounded_array![std140::uint, std140::uint(1), std140::uint(2)];
+    ///
+    /// assert_eq!(values.first(), Some(&std140::uint(1)));
+    /// ```
+    #[inline]
+    pub fn first(&self) -> Option<&T> {
+        self.0.first().map(|element| &element.0)
+    }
+
+    /// Returns the last element of the array, or `None` if it is empty.
+    #[inline]
+    pub fn last(&self) -> Option<&T> {
+        self.0.last().map(|element| &element.0)
+    }
+
+    /// Sorts the elements in ascending order, comparing the unwrapped elements.
+    ///
+    /// The sort is stable, see [slice::sort].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::uint;
+    ///
+    /// let mut materials = std140::unbounded_array![uint, uint(2), uint(0), uint(1)];
+    /// materials.sort();
+    ///
+    /// assert_eq!(materials, std140::unbounded_array![uint, uint(0), uint(1), uint(2)]);
+    /// ```
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.0.sort_by(|a, b| a.0.cmp(&b.0));
+    }
+
+    /// Sorts the elements with the comparator function `f`, which is called with the unwrapped
+    /// elements.
+    ///
+    /// The sort is stable, see [slice::sort_by].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::float;
+    ///
+    /// let mut depths = std140::unbounded_array![float, float(0.5), float(2.0), float(1.0)];
+    /// depths.sort_by(|a, b| b.0.total_cmp(&a.0));
+    ///
+    /// assert_eq!(depths, std140::unbounded_array![float, float(2.0), float(1.0), float(0.5)]);
+    /// ```
+    pub fn sort_by<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.0.sort_by(|a, b| f(&a.0, &b.0));
+    }
+
+    /// Removes consecutive repeated elements, comparing the unwrapped elements.
+    ///
+    /// Like [Vec::dedup], only adjacent duplicates are removed, so [sort][Self::sort] the array
+    /// first to remove all duplicates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::uint;
+    ///
+    /// let mut materials = std140::unbounded_array![uint, uint(0), uint(0), uint(1), uint(0)];
+    /// materials.dedup();
+    ///
+    /// assert_eq!(materials, std140::unbounded_array![uint, uint(0), uint(1), uint(0)]);
+    /// ```
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.0.dedup_by(|a, b| a.0 == b.0);
+    }
+
+    /// Removes consecutive elements that resolve to the same key, keeping the first of each run.
+    ///
+    /// The key function `key` is called with the unwrapped elements, see [Vec::dedup_by_key].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::{unbounded_array::unbounded_array, vec::vec4};
+    ///
+    /// let mut instances = std140::unbounded_array![
+    ///     vec4,
+    ///     vec4(0.0, 0.0, 0.0, 1.0),
+    ///     vec4(1.0, 0.0, 0.0, 1.0),
+    ///     vec4(2.0, 0.0, 0.0, 2.0)
+    /// ];
+    /// instances.dedup_by_key(|instance| instance.3.to_bits());
+    ///
+    /// assert_eq!(instances.len(), 2);
+    /// assert_eq!(instances.last(), Some(&vec4(2.0, 0.0, 0.0, 2.0)));
+    /// ```
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.0.dedup_by_key(|element| key(&mut element.0));
+    }
+
+    /// Returns a copy of the std140 representation of the array's elements, ready to be uploaded to
+    /// a GPU buffer.
+    ///
+    /// Every element occupies its 16 byte aligned slot, so the result is `len()` times the element
+    /// size rounded up to 16 bytes long. Padding bytes are zeroed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let values = std140::unbounded_array![std140::float, std140::float(1.0), std140::float(2.0)];
+    /// let bytes = values.to_std140_bytes();
+    ///
+    /// assert_eq!(bytes.len(), 32);
+    /// assert_eq!(bytes[4..16], [0; 12]);
+    /// assert_eq!(bytes[16..20], 2.0f32.to_ne_bytes());
+    /// ```
+    pub fn to_std140_bytes(&self) -> Vec<u8> {
+        let stride = ::std::mem::size_of::<AlignmentedElement<T>>();
+        let mut bytes = vec![0; self.0.len() * stride];
+
+        for (element, bytes) in self.0.iter().zip(bytes.chunks_exact_mut(stride)) {
+            element
+                .0
+                .write_std140_bytes(&mut bytes[..::std::mem::size_of::<T>()], ByteOrder::NATIVE);
+        }
+
+        bytes
+    }
 }
 
 impl<I> FromIterator<AlignmentedElement<I>> for unbounded_array<I>
@@ -33,12 +281,65 @@ where
     }
 }
 
+impl<T, const LEN: usize> TryFrom<unbounded_array<T>> for array<T, { LEN }>
+where
+    T: Std140ArrayElement,
+{
+    type Error = unbounded_array<T>;
+
+    /// Moves the elements of `unbounded` into a new fixed size array.
+    ///
+    /// Returns the original [unbounded_array] as the error if its length is not `LEN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std140::{array::array, uint};
+    ///
+    /// let values = std140::unbounded_array![uint, uint(0), uint(1)];
+    /// let values: array<uint, 2> = values.try_into().unwrap();
+    ///
+    /// assert_eq!(values, std140::array![uint(0), uint(1)]);
+    /// ```
+    fn try_from(unbounded: unbounded_array<T>) -> Result<Self, Self::Error> {
+        match unbounded.0.try_into() {
+            Ok(inner) => Ok(array::new(inner)),
+            Err(inner) => Err(unbounded_array(inner)),
+        }
+    }
+}
+
 impl<T> fmt::Debug for unbounded_array<T>
 where
     T: Std140ArrayElement + fmt::Debug,
 {
+    /// Formats the elements as a list.
+    ///
+    /// The alternate format (`{:#?}`) prefixes each element with its index, e.g. `[3]: uint(7)`,
+    /// which helps to locate elements in long arrays.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_list().entries(self.0.iter()).finish()
+        if !f.alternate() || self.0.is_empty() {
+            return f.debug_list().entries(self.0.iter()).finish();
+        }
+
+        f.write_str("[\n")?;
+
+        for (index, element) in self.0.iter().enumerate() {
+            let element = format!("{:#?}", element.0);
+
+            writeln!(f, "    [{}]: {},", index, element.replace('\n', "\n    "))?;
+        }
+
+        f.write_str("]")
+    }
+}
+
+impl<T> PartialEq for unbounded_array<T>
+where
+    T: Std140ArrayElement + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len() && self.0.iter().zip(other.0.iter()).all(|(a, b)| a.0 == b.0)
     }
 }
 
@@ -62,7 +363,58 @@ where
     }
 }
 
-unsafe impl<T> ReprStd140 for unbounded_array<T> where T: Std140ArrayElement {}
+/// Indexes the wrapped elements like a slice: a `usize` yields an [AlignmentedElement] and a range
+/// yields a slice of them, panicking on out of bounds indices just like slice indexing.
+impl<T, I> Index<I> for unbounded_array<T>
+where
+    T: Std140ArrayElement,
+    I: SliceIndex<[AlignmentedElement<T>]>,
+{
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<T, I> IndexMut<I> for unbounded_array<T>
+where
+    T: Std140ArrayElement,
+    I: SliceIndex<[AlignmentedElement<T>]>,
+{
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<T> unbounded_array<T>
+where
+    T: Std140ArrayElement,
+{
+    const TYPE_NAME: &'static TypeName = &TypeName::array(T::GLSL_NAME, None);
+}
+
+impl<T> GlslType for unbounded_array<T>
+where
+    T: Std140ArrayElement,
+{
+    const GLSL_NAME: &'static str = Self::TYPE_NAME.as_str();
+}
+
+unsafe impl<T> ReprStd140 for unbounded_array<T>
+where
+    T: Std140ArrayElement,
+{
+    const STD140_KIND: MemberKind = MemberKind::Array;
+    // The elements live on the heap, so none of the bytes of the array itself are std140 data.
+    const STD140_PADDING: usize = ::std::mem::size_of::<Self>();
+
+    /// Writes nothing: the elements of an unbounded array are not stored inline, so use
+    /// [to_std140_bytes][Self::to_std140_bytes] to upload them.
+    fn write_std140_bytes(&self, bytes: &mut [u8], _order: ByteOrder) {
+        assert_eq!(bytes.len(), ::std::mem::size_of::<Self>());
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -72,4 +424,16 @@ mod tests {
     fn it_works() {
         assert_eq!(16, std::mem::align_of::<unbounded_array<crate::uint>>());
     }
+
+    #[test]
+    fn first_last() {
+        let a =
+            crate::unbounded_array![crate::uint, crate::uint(1), crate::uint(2), crate::uint(3)];
+        assert_eq!(Some(&crate::uint(1)), a.first());
+        assert_eq!(Some(&crate::uint(3)), a.last());
+
+        let empty = unbounded_array::<crate::uint>::new(Vec::new());
+        assert_eq!(None, empty.first());
+        assert_eq!(None, empty.last());
+    }
 }
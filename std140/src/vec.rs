@@ -1,6 +1,40 @@
-use ::std::ops::{Index, IndexMut};
-
-use crate::{boolean, ReprStd140, Std140ArrayElement};
+use ::std::{
+    iter::{Product, Sum},
+    mem,
+    ops::{
+        Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Sub, SubAssign,
+    },
+    slice,
+};
+
+use crate::{
+    boolean, glsl::GlslType, layout::MemberKind, ByteOrder, ReprStd140, Std140ArrayElement,
+};
+
+/// Describes the dimensions and component type of a numeric vector type.
+///
+/// # Example
+///
+/// ```
+/// use std140::vec::{vec3, Vector};
+///
+/// assert_eq!(vec3::DIMENSIONS, 3);
+/// assert_eq!(vec3::from_scalar_slice(&[1.0, 2.0, 3.0]), vec3(1.0, 2.0, 3.0));
+/// ```
+pub trait Vector: Sized {
+    /// The number of components in the vector.
+    const DIMENSIONS: usize;
+
+    /// The primitive type of each component, e.g. `f32` for a `vec3`.
+    type Scalar;
+
+    /// Creates a vector from a slice holding its components in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `slice` is not [DIMENSIONS][Self::DIMENSIONS].
+    fn from_scalar_slice(slice: &[Self::Scalar]) -> Self;
+}
 
 /// A column vector of 2 [float][crate::float] values.
 ///
@@ -11,6 +45,7 @@ use crate::{boolean, ReprStd140, Std140ArrayElement};
 /// ```
 #[repr(C, align(8))]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct vec2(pub f32, pub f32);
 
 impl vec2 {
@@ -20,7 +55,14 @@ impl vec2 {
     }
 }
 
-unsafe impl ReprStd140 for vec2 {}
+impl GlslType for vec2 {
+    const GLSL_NAME: &'static str = "vec2";
+}
+
+unsafe impl ReprStd140 for vec2 {
+    const STD140_KIND: MemberKind = MemberKind::Vector;
+    const STD140_PADDING: usize = 0;
+}
 unsafe impl Std140ArrayElement for vec2 {}
 
 impl Index<usize> for vec2 {
@@ -30,7 +72,7 @@ impl Index<usize> for vec2 {
         match index {
             0 => &self.0,
             1 => &self.1,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for vec2 (len 2)"),
         }
     }
 }
@@ -40,13 +82,22 @@ impl IndexMut<usize> for vec2 {
         match index {
             0 => &mut self.0,
             1 => &mut self.1,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for vec2 (len 2)"),
         }
     }
 }
 
 /// A column vector of 3 [float][crate::float] values.
 ///
+/// # Padding
+///
+/// A `vec3` is aligned to 16 bytes and therefore occupies 16 bytes, of which only the first 12
+/// bytes hold the vector's components; the last 4 bytes are padding. When copying `vec3` values to
+/// the GPU the padding must be included (see [vec3::to_std140_bytes]), but when copying values back into
+/// a tightly packed representation such as `[f32; 3]` it must be skipped (see
+/// [vec3::meaningful_bytes]). Reading 4 floats per `vec3` from such a tightly packed
+/// representation reads the first component of the next vector instead.
+///
 /// # Example
 ///
 /// ```
@@ -54,6 +105,7 @@ impl IndexMut<usize> for vec2 {
 /// ```
 #[repr(C, align(16))]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct vec3(pub f32, pub f32, pub f32);
 
 impl vec3 {
@@ -63,7 +115,20 @@ impl vec3 {
     }
 }
 
-unsafe impl ReprStd140 for vec3 {}
+impl GlslType for vec3 {
+    const GLSL_NAME: &'static str = "vec3";
+}
+
+unsafe impl ReprStd140 for vec3 {
+    const STD140_KIND: MemberKind = MemberKind::Vector;
+    const STD140_PADDING: usize = 4;
+
+    fn write_std140_bytes(&self, bytes: &mut [u8], order: ByteOrder) {
+        assert_eq!(bytes.len(), mem::size_of::<Self>());
+
+        crate::write_scalars(&mut bytes[..12], self.meaningful_bytes(), 4, order);
+    }
+}
 unsafe impl Std140ArrayElement for vec3 {}
 
 impl Index<usize> for vec3 {
@@ -74,7 +139,7 @@ impl Index<usize> for vec3 {
             0 => &self.0,
             1 => &self.1,
             2 => &self.2,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for vec3 (len 3)"),
         }
     }
 }
@@ -85,7 +150,7 @@ impl IndexMut<usize> for vec3 {
             0 => &mut self.0,
             1 => &mut self.1,
             2 => &mut self.2,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for vec3 (len 3)"),
         }
     }
 }
@@ -99,6 +164,7 @@ impl IndexMut<usize> for vec3 {
 /// ```
 #[repr(C, align(16))]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct vec4(pub f32, pub f32, pub f32, pub f32);
 
 impl vec4 {
@@ -108,7 +174,14 @@ impl vec4 {
     }
 }
 
-unsafe impl ReprStd140 for vec4 {}
+impl GlslType for vec4 {
+    const GLSL_NAME: &'static str = "vec4";
+}
+
+unsafe impl ReprStd140 for vec4 {
+    const STD140_KIND: MemberKind = MemberKind::Vector;
+    const STD140_PADDING: usize = 0;
+}
 unsafe impl Std140ArrayElement for vec4 {}
 
 impl Index<usize> for vec4 {
@@ -120,7 +193,7 @@ impl Index<usize> for vec4 {
             1 => &self.1,
             2 => &self.2,
             3 => &self.3,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for vec4 (len 4)"),
         }
     }
 }
@@ -132,7 +205,7 @@ impl IndexMut<usize> for vec4 {
             1 => &mut self.1,
             2 => &mut self.2,
             3 => &mut self.3,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for vec4 (len 4)"),
         }
     }
 }
@@ -146,6 +219,7 @@ impl IndexMut<usize> for vec4 {
 /// ```
 #[repr(C, align(8))]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ivec2(pub i32, pub i32);
 
 impl ivec2 {
@@ -155,7 +229,14 @@ impl ivec2 {
     }
 }
 
-unsafe impl ReprStd140 for ivec2 {}
+impl GlslType for ivec2 {
+    const GLSL_NAME: &'static str = "ivec2";
+}
+
+unsafe impl ReprStd140 for ivec2 {
+    const STD140_KIND: MemberKind = MemberKind::Vector;
+    const STD140_PADDING: usize = 0;
+}
 unsafe impl Std140ArrayElement for ivec2 {}
 
 impl Index<usize> for ivec2 {
@@ -165,7 +246,7 @@ impl Index<usize> for ivec2 {
         match index {
             0 => &self.0,
             1 => &self.1,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for ivec2 (len 2)"),
         }
     }
 }
@@ -175,7 +256,7 @@ impl IndexMut<usize> for ivec2 {
         match index {
             0 => &mut self.0,
             1 => &mut self.1,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for ivec2 (len 2)"),
         }
     }
 }
@@ -189,6 +270,7 @@ impl IndexMut<usize> for ivec2 {
 /// ```
 #[repr(C, align(16))]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ivec3(pub i32, pub i32, pub i32);
 
 impl ivec3 {
@@ -198,7 +280,20 @@ impl ivec3 {
     }
 }
 
-unsafe impl ReprStd140 for ivec3 {}
+impl GlslType for ivec3 {
+    const GLSL_NAME: &'static str = "ivec3";
+}
+
+unsafe impl ReprStd140 for ivec3 {
+    const STD140_KIND: MemberKind = MemberKind::Vector;
+    const STD140_PADDING: usize = 4;
+
+    fn write_std140_bytes(&self, bytes: &mut [u8], order: ByteOrder) {
+        assert_eq!(bytes.len(), mem::size_of::<Self>());
+
+        crate::write_scalars(&mut bytes[..12], self.meaningful_bytes(), 4, order);
+    }
+}
 unsafe impl Std140ArrayElement for ivec3 {}
 
 impl Index<usize> for ivec3 {
@@ -209,7 +304,7 @@ impl Index<usize> for ivec3 {
             0 => &self.0,
             1 => &self.1,
             2 => &self.2,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for ivec3 (len 3)"),
         }
     }
 }
@@ -220,7 +315,7 @@ impl IndexMut<usize> for ivec3 {
             0 => &mut self.0,
             1 => &mut self.1,
             2 => &mut self.2,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for ivec3 (len 3)"),
         }
     }
 }
@@ -234,6 +329,7 @@ impl IndexMut<usize> for ivec3 {
 /// ```
 #[repr(C, align(16))]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ivec4(pub i32, pub i32, pub i32, pub i32);
 
 impl ivec4 {
@@ -243,7 +339,14 @@ impl ivec4 {
     }
 }
 
-unsafe impl ReprStd140 for ivec4 {}
+impl GlslType for ivec4 {
+    const GLSL_NAME: &'static str = "ivec4";
+}
+
+unsafe impl ReprStd140 for ivec4 {
+    const STD140_KIND: MemberKind = MemberKind::Vector;
+    const STD140_PADDING: usize = 0;
+}
 unsafe impl Std140ArrayElement for ivec4 {}
 
 impl Index<usize> for ivec4 {
@@ -255,7 +358,7 @@ impl Index<usize> for ivec4 {
             1 => &self.1,
             2 => &self.2,
             3 => &self.3,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for ivec4 (len 4)"),
         }
     }
 }
@@ -267,7 +370,7 @@ impl IndexMut<usize> for ivec4 {
             1 => &mut self.1,
             2 => &mut self.2,
             3 => &mut self.3,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for ivec4 (len 4)"),
         }
     }
 }
@@ -281,6 +384,7 @@ impl IndexMut<usize> for ivec4 {
 /// ```
 #[repr(C, align(8))]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct uvec2(pub u32, pub u32);
 
 impl uvec2 {
@@ -290,7 +394,14 @@ impl uvec2 {
     }
 }
 
-unsafe impl ReprStd140 for uvec2 {}
+impl GlslType for uvec2 {
+    const GLSL_NAME: &'static str = "uvec2";
+}
+
+unsafe impl ReprStd140 for uvec2 {
+    const STD140_KIND: MemberKind = MemberKind::Vector;
+    const STD140_PADDING: usize = 0;
+}
 unsafe impl Std140ArrayElement for uvec2 {}
 
 impl Index<usize> for uvec2 {
@@ -300,7 +411,7 @@ impl Index<usize> for uvec2 {
         match index {
             0 => &self.0,
             1 => &self.1,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for uvec2 (len 2)"),
         }
     }
 }
@@ -310,7 +421,7 @@ impl IndexMut<usize> for uvec2 {
         match index {
             0 => &mut self.0,
             1 => &mut self.1,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for uvec2 (len 2)"),
         }
     }
 }
@@ -324,6 +435,7 @@ impl IndexMut<usize> for uvec2 {
 /// ```
 #[repr(C, align(16))]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct uvec3(pub u32, pub u32, pub u32);
 
 impl uvec3 {
@@ -333,7 +445,20 @@ impl uvec3 {
     }
 }
 
-unsafe impl ReprStd140 for uvec3 {}
+impl GlslType for uvec3 {
+    const GLSL_NAME: &'static str = "uvec3";
+}
+
+unsafe impl ReprStd140 for uvec3 {
+    const STD140_KIND: MemberKind = MemberKind::Vector;
+    const STD140_PADDING: usize = 4;
+
+    fn write_std140_bytes(&self, bytes: &mut [u8], order: ByteOrder) {
+        assert_eq!(bytes.len(), mem::size_of::<Self>());
+
+        crate::write_scalars(&mut bytes[..12], self.meaningful_bytes(), 4, order);
+    }
+}
 unsafe impl Std140ArrayElement for uvec3 {}
 
 impl Index<usize> for uvec3 {
@@ -344,7 +469,7 @@ impl Index<usize> for uvec3 {
             0 => &self.0,
             1 => &self.1,
             2 => &self.2,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for uvec3 (len 3)"),
         }
     }
 }
@@ -355,7 +480,7 @@ impl IndexMut<usize> for uvec3 {
             0 => &mut self.0,
             1 => &mut self.1,
             2 => &mut self.2,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for uvec3 (len 3)"),
         }
     }
 }
@@ -369,6 +494,7 @@ impl IndexMut<usize> for uvec3 {
 /// ```
 #[repr(C, align(16))]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct uvec4(pub u32, pub u32, pub u32, pub u32);
 
 impl uvec4 {
@@ -378,7 +504,14 @@ impl uvec4 {
     }
 }
 
-unsafe impl ReprStd140 for uvec4 {}
+impl GlslType for uvec4 {
+    const GLSL_NAME: &'static str = "uvec4";
+}
+
+unsafe impl ReprStd140 for uvec4 {
+    const STD140_KIND: MemberKind = MemberKind::Vector;
+    const STD140_PADDING: usize = 0;
+}
 unsafe impl Std140ArrayElement for uvec4 {}
 
 impl Index<usize> for uvec4 {
@@ -390,7 +523,7 @@ impl Index<usize> for uvec4 {
             1 => &self.1,
             2 => &self.2,
             3 => &self.3,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for uvec4 (len 4)"),
         }
     }
 }
@@ -402,7 +535,7 @@ impl IndexMut<usize> for uvec4 {
             1 => &mut self.1,
             2 => &mut self.2,
             3 => &mut self.3,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for uvec4 (len 4)"),
         }
     }
 }
@@ -416,6 +549,7 @@ impl IndexMut<usize> for uvec4 {
 /// ```
 #[repr(C, align(8))]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct bvec2(pub boolean, pub boolean);
 
 impl bvec2 {
@@ -425,7 +559,14 @@ impl bvec2 {
     }
 }
 
-unsafe impl ReprStd140 for bvec2 {}
+impl GlslType for bvec2 {
+    const GLSL_NAME: &'static str = "bvec2";
+}
+
+unsafe impl ReprStd140 for bvec2 {
+    const STD140_KIND: MemberKind = MemberKind::Vector;
+    const STD140_PADDING: usize = 0;
+}
 unsafe impl Std140ArrayElement for bvec2 {}
 
 impl Index<usize> for bvec2 {
@@ -435,7 +576,7 @@ impl Index<usize> for bvec2 {
         match index {
             0 => &self.0,
             1 => &self.1,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for bvec2 (len 2)"),
         }
     }
 }
@@ -445,7 +586,7 @@ impl IndexMut<usize> for bvec2 {
         match index {
             0 => &mut self.0,
             1 => &mut self.1,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for bvec2 (len 2)"),
         }
     }
 }
@@ -459,6 +600,7 @@ impl IndexMut<usize> for bvec2 {
 /// ```
 #[repr(C, align(16))]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct bvec3(pub boolean, pub boolean, pub boolean);
 
 impl bvec3 {
@@ -468,7 +610,20 @@ impl bvec3 {
     }
 }
 
-unsafe impl ReprStd140 for bvec3 {}
+impl GlslType for bvec3 {
+    const GLSL_NAME: &'static str = "bvec3";
+}
+
+unsafe impl ReprStd140 for bvec3 {
+    const STD140_KIND: MemberKind = MemberKind::Vector;
+    const STD140_PADDING: usize = 4;
+
+    fn write_std140_bytes(&self, bytes: &mut [u8], order: ByteOrder) {
+        assert_eq!(bytes.len(), mem::size_of::<Self>());
+
+        crate::write_scalars(&mut bytes[..12], self.meaningful_bytes(), 4, order);
+    }
+}
 unsafe impl Std140ArrayElement for bvec3 {}
 
 impl Index<usize> for bvec3 {
@@ -479,7 +634,7 @@ impl Index<usize> for bvec3 {
             0 => &self.0,
             1 => &self.1,
             2 => &self.2,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for bvec3 (len 3)"),
         }
     }
 }
@@ -490,7 +645,7 @@ impl IndexMut<usize> for bvec3 {
             0 => &mut self.0,
             1 => &mut self.1,
             2 => &mut self.2,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for bvec3 (len 3)"),
         }
     }
 }
@@ -509,6 +664,7 @@ impl IndexMut<usize> for bvec3 {
 /// ```
 #[repr(C, align(16))]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct bvec4(pub boolean, pub boolean, pub boolean, pub boolean);
 
 impl bvec4 {
@@ -523,7 +679,14 @@ impl bvec4 {
     }
 }
 
-unsafe impl ReprStd140 for bvec4 {}
+impl GlslType for bvec4 {
+    const GLSL_NAME: &'static str = "bvec4";
+}
+
+unsafe impl ReprStd140 for bvec4 {
+    const STD140_KIND: MemberKind = MemberKind::Vector;
+    const STD140_PADDING: usize = 0;
+}
 unsafe impl Std140ArrayElement for bvec4 {}
 
 impl Index<usize> for bvec4 {
@@ -535,7 +698,7 @@ impl Index<usize> for bvec4 {
             1 => &self.1,
             2 => &self.2,
             3 => &self.3,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for bvec4 (len 4)"),
         }
     }
 }
@@ -547,7 +710,563 @@ impl IndexMut<usize> for bvec4 {
             1 => &mut self.1,
             2 => &mut self.2,
             3 => &mut self.3,
-            _ => panic!("Index out of bounds"),
+            _ => panic!("index {index} out of bounds for bvec4 (len 4)"),
         }
     }
 }
+
+macro_rules! impl_vec_map {
+    ($name:ident, $scalar:ty, $($index:tt),+) => {
+        impl $name {
+            #[doc = concat!("Returns a new `", stringify!($name), "` with `f` applied to each component.")]
+            ///
+            /// Components are visited in order, starting with the first component.
+            pub fn map<F>(self, mut f: F) -> Self
+            where
+                F: FnMut($scalar) -> $scalar,
+            {
+                $name($(f(self.$index)),+)
+            }
+        }
+    };
+}
+
+impl_vec_map!(vec2, f32, 0, 1);
+impl_vec_map!(vec3, f32, 0, 1, 2);
+impl_vec_map!(vec4, f32, 0, 1, 2, 3);
+impl_vec_map!(ivec2, i32, 0, 1);
+impl_vec_map!(ivec3, i32, 0, 1, 2);
+impl_vec_map!(ivec4, i32, 0, 1, 2, 3);
+impl_vec_map!(uvec2, u32, 0, 1);
+impl_vec_map!(uvec3, u32, 0, 1, 2);
+impl_vec_map!(uvec4, u32, 0, 1, 2, 3);
+impl_vec_map!(bvec2, boolean, 0, 1);
+impl_vec_map!(bvec3, boolean, 0, 1, 2);
+impl_vec_map!(bvec4, boolean, 0, 1, 2, 3);
+
+macro_rules! impl_vec_reductions {
+    ($name:ident, $scalar:ty, $first:tt, $($index:tt),+) => {
+        impl $name {
+            /// Returns the sum of all components.
+            pub fn sum(self) -> $scalar {
+                self.$first $(+ self.$index)+
+            }
+
+            /// Returns the product of all components.
+            pub fn product(self) -> $scalar {
+                self.$first $(* self.$index)+
+            }
+
+            /// Returns the smallest component.
+            pub fn min_element(self) -> $scalar {
+                self.$first $(.min(self.$index))+
+            }
+
+            /// Returns the largest component.
+            pub fn max_element(self) -> $scalar {
+                self.$first $(.max(self.$index))+
+            }
+        }
+    };
+}
+
+impl_vec_reductions!(vec2, f32, 0, 1);
+impl_vec_reductions!(vec3, f32, 0, 1, 2);
+impl_vec_reductions!(vec4, f32, 0, 1, 2, 3);
+impl_vec_reductions!(ivec2, i32, 0, 1);
+impl_vec_reductions!(ivec3, i32, 0, 1, 2);
+impl_vec_reductions!(ivec4, i32, 0, 1, 2, 3);
+impl_vec_reductions!(uvec2, u32, 0, 1);
+impl_vec_reductions!(uvec3, u32, 0, 1, 2);
+impl_vec_reductions!(uvec4, u32, 0, 1, 2, 3);
+
+macro_rules! impl_vec_neg {
+    ($name:ident, $($index:tt),+) => {
+        impl Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                $name($(-self.$index),+)
+            }
+        }
+    };
+}
+
+impl_vec_neg!(vec2, 0, 1);
+impl_vec_neg!(vec3, 0, 1, 2);
+impl_vec_neg!(vec4, 0, 1, 2, 3);
+impl_vec_neg!(ivec2, 0, 1);
+impl_vec_neg!(ivec3, 0, 1, 2);
+impl_vec_neg!(ivec4, 0, 1, 2, 3);
+
+macro_rules! impl_vec_const_eq {
+    ($($name:ident: |$a:ident, $b:ident| $eq:expr => ($($index:tt),+)),+) => {
+        $(
+            impl $name {
+                /// Compares two vectors component-wise in a `const` context, where [PartialEq] is
+                /// not usable.
+                ///
+                /// Floating point components are compared bitwise, so unlike `==` this considers
+                /// `NaN` equal to a `NaN` with the same bits, and `0.0` different from `-0.0`.
+                pub const fn const_eq(&self, other: &Self) -> bool {
+                    true $(&& {
+                        let ($a, $b) = (self.$index, other.$index);
+
+                        $eq
+                    })+
+                }
+            }
+        )+
+    };
+}
+
+impl_vec_const_eq!(
+    vec2: |a, b| a.to_bits() == b.to_bits() => (0, 1),
+    vec3: |a, b| a.to_bits() == b.to_bits() => (0, 1, 2),
+    vec4: |a, b| a.to_bits() == b.to_bits() => (0, 1, 2, 3),
+    ivec2: |a, b| a == b => (0, 1),
+    ivec3: |a, b| a == b => (0, 1, 2),
+    ivec4: |a, b| a == b => (0, 1, 2, 3),
+    uvec2: |a, b| a == b => (0, 1),
+    uvec3: |a, b| a == b => (0, 1, 2),
+    uvec4: |a, b| a == b => (0, 1, 2, 3),
+    bvec2: |a, b| a.const_eq(&b) => (0, 1),
+    bvec3: |a, b| a.const_eq(&b) => (0, 1, 2),
+    bvec4: |a, b| a.const_eq(&b) => (0, 1, 2, 3)
+);
+
+macro_rules! impl_vec_bits {
+    ($name:ident, $len:literal, $($index:tt),+) => {
+        impl $name {
+            /// Returns the raw bit representation of each component.
+            pub const fn to_bits(self) -> [u32; $len] {
+                [$(self.$index.to_bits()),+]
+            }
+
+            /// Creates a vector from the raw bit representation of each component.
+            pub const fn from_bits(bits: [u32; $len]) -> Self {
+                $name($(f32::from_bits(bits[$index])),+)
+            }
+        }
+    };
+}
+
+impl_vec_bits!(vec2, 2, 0, 1);
+impl_vec_bits!(vec3, 3, 0, 1, 2);
+impl_vec_bits!(vec4, 4, 0, 1, 2, 3);
+
+macro_rules! impl_vec3_bytes {
+    ($($name:ident),+) => {
+        $(
+            impl $name {
+                #[doc = concat!("Returns the full 16 byte std140 representation of the `", stringify!($name), "`.")]
+                ///
+                /// The last 4 bytes are padding and are always zero; they are included so that
+                /// consecutive vectors are spaced correctly when copied to the GPU.
+                pub fn to_std140_bytes(&self) -> [u8; 16] {
+                    let mut bytes = [0; 16];
+                    bytes[..12].copy_from_slice(self.meaningful_bytes());
+                    bytes
+                }
+
+                /// Returns the 12 bytes that hold the vector's components, excluding the trailing
+                /// padding.
+                pub fn meaningful_bytes(&self) -> &[u8] {
+                    // SAFETY: the pointer is derived from a valid reference and the 3 components
+                    // occupy the first 12 bytes of the referenced value.
+                    unsafe { slice::from_raw_parts(self as *const Self as *const u8, 12) }
+                }
+
+                /// Returns the 4 trailing padding bytes, e.g. to check whether a buffer read back
+                /// from the GPU holds unexpected data.
+                ///
+                /// # Safety
+                ///
+                /// The padding bytes must be initialized, e.g. because the vector was written by
+                /// [set_padding][Self::set_padding] or mapped from a GPU buffer and has not been
+                /// copied or moved since. A vector constructed in Rust has uninitialized padding,
+                /// and reading it is undefined behavior.
+                pub unsafe fn padding_bytes(&self) -> [u8; 4] {
+                    // SAFETY: the pointer is derived from a valid reference, the 4 bytes after the
+                    // 3 components lie within the referenced value and the caller guarantees that
+                    // they are initialized.
+                    unsafe { (self as *const Self as *const u8).add(12).cast::<[u8; 4]>().read() }
+                }
+
+                /// Overwrites the 4 trailing padding bytes, e.g. to poison them with a recognizable
+                /// pattern when diagnosing buffer corruption.
+                ///
+                /// The components are left untouched. The written bytes only live in this
+                /// particular place in memory: any typed copy or move of the vector, including
+                /// passing it by value or assigning it to another variable, copies only the
+                /// components and leaves the padding of the copy uninitialized.
+                pub fn set_padding(&mut self, bytes: [u8; 4]) {
+                    // SAFETY: the pointer is derived from a valid mutable reference and the 4 bytes
+                    // after the 3 components are padding within the referenced value, which may
+                    // hold any value.
+                    unsafe {
+                        let padding = (self as *mut Self as *mut u8).add(12);
+                        padding.copy_from_nonoverlapping(bytes.as_ptr(), 4);
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_vec3_bytes!(vec3, ivec3, uvec3, bvec3);
+
+macro_rules! impl_vec3_components {
+    ($($name:ident: $scalar:ty),+) => {
+        $(
+            impl $name {
+                /// Returns the 3 components of the vector, leaving out the trailing padding.
+                ///
+                /// Use this when reading vectors back from the GPU to make clear that the padding,
+                /// which may hold any value, is not read.
+                pub fn truncate_padding(self) -> [$scalar; 3] {
+                    [self.0, self.1, self.2]
+                }
+            }
+        )+
+    };
+}
+
+impl_vec3_components!(vec3: f32, ivec3: i32, uvec3: u32);
+
+macro_rules! impl_vec_extend_truncate {
+    ($($small:ident($($index:tt),+) <=> $large:ident: $scalar:ty),+) => {
+        $(
+            impl $small {
+                #[doc = concat!("Extends the vector to a [", stringify!($large), "] with `value` as its last component, like the GLSL constructor `", stringify!($large), "(v, value)`.")]
+                pub fn extend(self, value: $scalar) -> $large {
+                    $large($(self.$index,)+ value)
+                }
+            }
+
+            impl $large {
+                #[doc = concat!("Drops the last component, returning a [", stringify!($small), "].")]
+                pub fn truncate(self) -> $small {
+                    $small($(self.$index),+)
+                }
+            }
+        )+
+    };
+}
+
+impl_vec_extend_truncate!(
+    vec2(0, 1) <=> vec3: f32,
+    vec3(0, 1, 2) <=> vec4: f32,
+    ivec2(0, 1) <=> ivec3: i32,
+    ivec3(0, 1, 2) <=> ivec4: i32,
+    uvec2(0, 1) <=> uvec3: u32,
+    uvec3(0, 1, 2) <=> uvec4: u32
+);
+
+macro_rules! impl_vec_as_ref {
+    ($($name:ident: $len:literal),+) => {
+        $(
+            #[doc = concat!("Views the components of the `", stringify!($name), "`, excluding any trailing padding.")]
+            impl AsRef<[f32; $len]> for $name {
+                fn as_ref(&self) -> &[f32; $len] {
+                    // SAFETY: the vector is `repr(C)` and its components are stored contiguously
+                    // at its start, so they form a valid `[f32; $len]`, which is less strictly
+                    // aligned than the vector. The returned reference borrows from `self`.
+                    unsafe { &*(self as *const Self as *const [f32; $len]) }
+                }
+            }
+
+            impl AsRef<[f32]> for $name {
+                fn as_ref(&self) -> &[f32] {
+                    AsRef::<[f32; $len]>::as_ref(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_vec_as_ref!(vec2: 2, vec3: 3, vec4: 4);
+
+macro_rules! impl_vec_op {
+    ($name:ident, $scalar:ty, $op:ident, $op_fn:ident, $op_assign:ident, $op_assign_fn:ident, $token:tt, $($index:tt),+) => {
+        impl $op for $name {
+            type Output = Self;
+
+            fn $op_fn(self, rhs: Self) -> Self::Output {
+                $name($(self.$index $token rhs.$index),+)
+            }
+        }
+
+        impl $op<$scalar> for $name {
+            type Output = Self;
+
+            fn $op_fn(self, rhs: $scalar) -> Self::Output {
+                $name($(self.$index $token rhs),+)
+            }
+        }
+
+        impl $op_assign for $name {
+            fn $op_assign_fn(&mut self, rhs: Self) {
+                *self = *self $token rhs;
+            }
+        }
+
+        impl $op_assign<$scalar> for $name {
+            fn $op_assign_fn(&mut self, rhs: $scalar) {
+                *self = *self $token rhs;
+            }
+        }
+    };
+}
+
+macro_rules! impl_vec_arithmetic {
+    ($name:ident, $scalar:ty, $one:literal, $($index:tt),+) => {
+        impl_vec_op!($name, $scalar, Add, add, AddAssign, add_assign, +, $($index),+);
+        impl_vec_op!($name, $scalar, Sub, sub, SubAssign, sub_assign, -, $($index),+);
+        impl_vec_op!($name, $scalar, Mul, mul, MulAssign, mul_assign, *, $($index),+);
+        impl_vec_op!($name, $scalar, Div, div, DivAssign, div_assign, /, $($index),+);
+
+        impl Sum for $name {
+            fn sum<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = Self>,
+            {
+                iter.fold($name::zero(), Add::add)
+            }
+        }
+
+        impl<'a> Sum<&'a $name> for $name {
+            fn sum<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = &'a Self>,
+            {
+                iter.copied().sum()
+            }
+        }
+
+        impl Product for $name {
+            fn product<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = Self>,
+            {
+                iter.fold($name::zero().map(|_| $one), Mul::mul)
+            }
+        }
+
+        impl<'a> Product<&'a $name> for $name {
+            fn product<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = &'a Self>,
+            {
+                iter.copied().product()
+            }
+        }
+    };
+}
+
+impl_vec_arithmetic!(vec2, f32, 1.0, 0, 1);
+impl_vec_arithmetic!(vec3, f32, 1.0, 0, 1, 2);
+impl_vec_arithmetic!(vec4, f32, 1.0, 0, 1, 2, 3);
+impl_vec_arithmetic!(ivec2, i32, 1, 0, 1);
+impl_vec_arithmetic!(ivec3, i32, 1, 0, 1, 2);
+impl_vec_arithmetic!(ivec4, i32, 1, 0, 1, 2, 3);
+impl_vec_arithmetic!(uvec2, u32, 1, 0, 1);
+impl_vec_arithmetic!(uvec3, u32, 1, 0, 1, 2);
+impl_vec_arithmetic!(uvec4, u32, 1, 0, 1, 2, 3);
+
+macro_rules! impl_vec_lerp {
+    ($($name:ident),+) => {
+        $(
+            impl $name {
+                /// Linearly interpolates between `self` and `other` by `t`, componentwise.
+                ///
+                /// This mirrors GLSL's `mix`: `t` is not clamped, so values outside `[0, 1]`
+                /// extrapolate beyond `self` and `other`.
+                pub fn lerp(self, other: Self, t: f32) -> Self {
+                    self + (other - self) * t
+                }
+            }
+        )+
+    };
+}
+
+impl_vec_lerp!(vec2, vec3, vec4);
+
+macro_rules! impl_bvec_logic {
+    ($name:ident, $($index:tt),+) => {
+        impl $name {
+            /// Returns `true` if every component is [boolean::True], like the GLSL `all` function.
+            pub fn all(self) -> bool {
+                $(self.$index == boolean::True)&&+
+            }
+
+            /// Returns `true` if any component is [boolean::True], like the GLSL `any` function.
+            pub fn any(self) -> bool {
+                $(self.$index == boolean::True)||+
+            }
+        }
+
+        impl Not for $name {
+            type Output = Self;
+
+            /// Flips each component, like the GLSL `not` function.
+            fn not(self) -> Self::Output {
+                self.map(Not::not)
+            }
+        }
+    };
+}
+
+impl_bvec_logic!(bvec2, 0, 1);
+impl_bvec_logic!(bvec3, 0, 1, 2);
+impl_bvec_logic!(bvec4, 0, 1, 2, 3);
+
+macro_rules! impl_bvec_bits {
+    ($name:ident, $from_bits:ident, $($index:tt),+) => {
+        #[doc = concat!("Expands the low bits of `mask` into a [", stringify!($name), "], bit `i` becoming component `i`.")]
+        ///
+        /// Bits beyond the vector's width are ignored.
+        pub fn $from_bits(mask: u32) -> $name {
+            $name($(boolean::from(mask & (1 << $index) != 0)),+)
+        }
+
+        impl $name {
+            #[doc = concat!("Packs the components into the low bits of a `u32`, the inverse of [", stringify!($from_bits), "].")]
+            pub fn to_bits(self) -> u32 {
+                $(((self.$index == boolean::True) as u32) << $index)|+
+            }
+        }
+    };
+}
+
+impl_bvec_bits!(bvec2, bvec2_from_bits, 0, 1);
+impl_bvec_bits!(bvec3, bvec3_from_bits, 0, 1, 2);
+impl_bvec_bits!(bvec4, bvec4_from_bits, 0, 1, 2, 3);
+
+macro_rules! impl_vec_float_checks {
+    ($name:ident, $($index:tt),+) => {
+        impl $name {
+            /// Returns `true` if every component is neither infinite nor NaN.
+            pub fn is_finite(self) -> bool {
+                $(self.$index.is_finite())&&+
+            }
+
+            /// Returns `true` if any component is NaN.
+            pub fn is_nan(self) -> bool {
+                $(self.$index.is_nan())||+
+            }
+
+            /// Returns the componentwise absolute value.
+            pub fn abs(self) -> Self {
+                self.map(f32::abs)
+            }
+        }
+    };
+}
+
+impl_vec_float_checks!(vec2, 0, 1);
+impl_vec_float_checks!(vec3, 0, 1, 2);
+impl_vec_float_checks!(vec4, 0, 1, 2, 3);
+
+macro_rules! impl_vec_geometric {
+    ($($name:ident),+) => {
+        $(
+            impl $name {
+                /// Returns the dot product of `self` and `other`, like the GLSL `dot` function.
+                pub fn dot(self, other: Self) -> f32 {
+                    (self * other).sum()
+                }
+
+                /// Returns the length of `self`, like the GLSL `length` function.
+                pub fn length(self) -> f32 {
+                    self.dot(self).sqrt()
+                }
+
+                /// Returns the distance between `self` and `other`, like the GLSL `distance`
+                /// function.
+                pub fn distance(self, other: Self) -> f32 {
+                    (self - other).length()
+                }
+
+                /// Returns the angle between `self` and `other` in radians, in the range `0..=PI`.
+                ///
+                /// The cosine of the angle is clamped to `-1.0..=1.0` before taking its arc
+                /// cosine, so rounding errors for (nearly) parallel vectors don't produce NaN.
+                /// Returns NaN if either vector has zero length.
+                pub fn angle_between(self, other: Self) -> f32 {
+                    let cos = self.dot(other) / (self.length() * other.length());
+
+                    cos.clamp(-1.0, 1.0).acos()
+                }
+
+                /// Returns the projection of `self` onto the direction of `onto`.
+                ///
+                /// Returns a vector of NaNs if `onto` has zero length.
+                pub fn project_onto(self, onto: Self) -> Self {
+                    onto * (self.dot(onto) / onto.dot(onto))
+                }
+
+                /// Returns the direction of the incident vector `self` reflected off a surface
+                /// with the given `normal`, like the GLSL `reflect` function.
+                ///
+                /// `normal` should be normalized for the result to be meaningful.
+                pub fn reflect(self, normal: Self) -> Self {
+                    self - normal * (2.0 * normal.dot(self))
+                }
+
+                /// Returns the refraction vector for the incident vector `self`, the surface
+                /// `normal` and the ratio of indices of refraction `eta`, like the GLSL `refract`
+                /// function.
+                ///
+                /// Returns a zero vector in case of total internal reflection. Both `self` and
+                /// `normal` should be normalized for the result to be meaningful.
+                pub fn refract(self, normal: Self, eta: f32) -> Self {
+                    let n_dot_i = normal.dot(self);
+                    let k = 1.0 - eta * eta * (1.0 - n_dot_i * n_dot_i);
+
+                    if k < 0.0 {
+                        Self::zero()
+                    } else {
+                        self * eta - normal * (eta * n_dot_i + k.sqrt())
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_vec_geometric!(vec2, vec3, vec4);
+
+macro_rules! impl_vector {
+    ($($name:ident: [$scalar:ty; $len:literal] => ($($index:tt),+)),+) => {
+        $(
+            impl Vector for $name {
+                const DIMENSIONS: usize = $len;
+
+                type Scalar = $scalar;
+
+                fn from_scalar_slice(slice: &[Self::Scalar]) -> Self {
+                    assert_eq!(
+                        slice.len(),
+                        $len,
+                        concat!("slice length does not match the ", stringify!($name), " length")
+                    );
+
+                    $name($(slice[$index]),+)
+                }
+            }
+        )+
+    };
+}
+
+impl_vector!(
+    vec2: [f32; 2] => (0, 1),
+    vec3: [f32; 3] => (0, 1, 2),
+    vec4: [f32; 4] => (0, 1, 2, 3),
+    ivec2: [i32; 2] => (0, 1),
+    ivec3: [i32; 3] => (0, 1, 2),
+    ivec4: [i32; 4] => (0, 1, 2, 3),
+    uvec2: [u32; 2] => (0, 1),
+    uvec3: [u32; 3] => (0, 1, 2),
+    uvec4: [u32; 4] => (0, 1, 2, 3)
+);
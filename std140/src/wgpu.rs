@@ -0,0 +1,61 @@
+//! [wgpu](https://docs.rs/wgpu) support, available with the `wgpu` feature.
+//!
+//! Targets wgpu 30.
+
+use ::wgpu::util::DeviceExt;
+
+use crate::Std140Struct;
+
+/// Extends [wgpu::Device] with methods creating buffers from std140 structs.
+///
+/// # Example
+///
+/// ```
+/// use std140::wgpu::Std140BufferExt;
+///
+/// #[std140::repr_std140]
+/// struct Camera {
+///     view_projection: std140::mat::mat4x4,
+/// }
+///
+/// fn create_camera_buffer(device: &wgpu::Device) -> wgpu::Buffer {
+///     let camera = Camera {
+///         view_projection: std140::mat::mat4x4::identity(),
+///     };
+///
+///     device.create_std140_uniform(Some("camera"), &camera, wgpu::BufferUsages::COPY_DST)
+/// }
+/// ```
+pub trait Std140BufferExt {
+    /// Creates a uniform buffer holding the std140 representation of `data`.
+    ///
+    /// The buffer is exactly as large as the struct and always has [wgpu::BufferUsages::UNIFORM];
+    /// pass any further usages, such as [wgpu::BufferUsages::COPY_DST] for buffers that are updated
+    /// later, in `usage`.
+    fn create_std140_uniform<T>(
+        &self,
+        label: Option<&str>,
+        data: &T,
+        usage: ::wgpu::BufferUsages,
+    ) -> ::wgpu::Buffer
+    where
+        T: Std140Struct;
+}
+
+impl Std140BufferExt for ::wgpu::Device {
+    fn create_std140_uniform<T>(
+        &self,
+        label: Option<&str>,
+        data: &T,
+        usage: ::wgpu::BufferUsages,
+    ) -> ::wgpu::Buffer
+    where
+        T: Std140Struct,
+    {
+        self.create_buffer_init(&::wgpu::util::BufferInitDescriptor {
+            label,
+            contents: &data.to_std140_bytes(),
+            usage: usage | ::wgpu::BufferUsages::UNIFORM,
+        })
+    }
+}
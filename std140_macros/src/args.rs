@@ -0,0 +1,287 @@
+use ::syn::{
+    parse::{Parse, ParseStream},
+    Error, Ident, Lit, LitInt, Meta, NestedMeta, Path, Token,
+};
+
+/// The arguments passed to a `#[repr_std140(...)]` attribute.
+#[derive(Default)]
+pub struct ReprStd140Args {
+    /// Generate `with_<field>` setters for every field.
+    pub builder: bool,
+    /// Generate a `GLSL_DECL` const holding the matching GLSL uniform block declaration.
+    pub glsl: bool,
+    /// Generate a `PADDING_REPORT` const describing the padding in the struct's layout.
+    pub warn_padding: bool,
+    /// Rewrite fields of Rust array types `[T; N]` to std140 arrays.
+    pub wrap_arrays: bool,
+    /// Generate a `wgsl_definition` function returning the matching WGSL struct definition.
+    pub wgsl: bool,
+    /// Generate `From` conversions to and from this plain mirror struct.
+    pub mirror: Option<Path>,
+    /// Generate a plain mirror struct with this name, and `From` conversions to and from it.
+    pub view: Option<Ident>,
+    /// Generate a `BINDING` const holding the uniform block's binding point.
+    pub binding: Option<LitInt>,
+    /// Generate a `SET` const holding the uniform block's descriptor set.
+    pub set: Option<LitInt>,
+    /// Implement `AsRef<[u8]>` for a struct without padding.
+    pub as_ref: bool,
+}
+
+impl Parse for ReprStd140Args {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = ReprStd140Args::default();
+        let mut errors = Errors::default();
+
+        while !input.is_empty() {
+            // `view = Name` takes a type name rather than a literal, so it isn't a `NestedMeta`.
+            if input.peek(Ident) && input.peek2(Token![=]) && !input.peek3(Lit) {
+                let path: Path = input.parse()?;
+                input.parse::<Token![=]>()?;
+                let value: Ident = input.parse()?;
+
+                if !path.is_ident("view") {
+                    errors.push(Error::new_spanned(value, "expected literal"));
+                } else if args.view.is_some() {
+                    errors.push(duplicate_argument(&path));
+                } else {
+                    args.view = Some(value);
+                }
+
+                if !input.is_empty() {
+                    input.parse::<Token![,]>()?;
+                }
+                continue;
+            }
+
+            let nested: NestedMeta = input.parse()?;
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+
+            match nested {
+                NestedMeta::Meta(meta) if meta.path().is_ident("builder") => {
+                    if let Err(error) = parse_flag(&meta, &mut args.builder) {
+                        errors.push(error);
+                    }
+                }
+                NestedMeta::Meta(meta) if meta.path().is_ident("glsl") => {
+                    if let Err(error) = parse_flag(&meta, &mut args.glsl) {
+                        errors.push(error);
+                    }
+                }
+                NestedMeta::Meta(meta) if meta.path().is_ident("warn_padding") => {
+                    if let Err(error) = parse_flag(&meta, &mut args.warn_padding) {
+                        errors.push(error);
+                    }
+                }
+                NestedMeta::Meta(meta) if meta.path().is_ident("wrap_arrays") => {
+                    if let Err(error) = parse_flag(&meta, &mut args.wrap_arrays) {
+                        errors.push(error);
+                    }
+                }
+                NestedMeta::Meta(meta) if meta.path().is_ident("wgsl") => {
+                    if let Err(error) = parse_flag(&meta, &mut args.wgsl) {
+                        errors.push(error);
+                    }
+                }
+                NestedMeta::Meta(meta) if meta.path().is_ident("mirror") => {
+                    if let Err(error) = parse_type_path(&meta, &mut args.mirror) {
+                        errors.push(error);
+                    }
+                }
+                NestedMeta::Meta(meta) if meta.path().is_ident("view") => {
+                    errors.push(Error::new_spanned(
+                        &meta,
+                        "#[repr_std140] argument `view` expects a struct name, e.g. `view = Name`",
+                    ))
+                }
+                NestedMeta::Meta(meta) if meta.path().is_ident("binding") => {
+                    if let Err(error) = parse_u32(&meta, &mut args.binding) {
+                        errors.push(error);
+                    }
+                }
+                NestedMeta::Meta(meta) if meta.path().is_ident("set") => {
+                    if let Err(error) = parse_u32(&meta, &mut args.set) {
+                        errors.push(error);
+                    }
+                }
+                NestedMeta::Meta(meta) if meta.path().is_ident("as_ref") => {
+                    if let Err(error) = parse_flag(&meta, &mut args.as_ref) {
+                        errors.push(error);
+                    }
+                }
+                NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("derive") => {
+                    for nested in &list.nested {
+                        errors.push(derive_argument(nested));
+                    }
+                }
+                NestedMeta::Meta(meta) => errors.push(unknown_argument(&meta)),
+                NestedMeta::Lit(lit) => errors.push(Error::new_spanned(
+                    lit,
+                    "expected a #[repr_std140] argument name, found a literal",
+                )),
+            }
+        }
+
+        if let (Some(mirror), Some(_)) = (&args.mirror, &args.view) {
+            errors.push(Error::new_spanned(
+                mirror,
+                "#[repr_std140] arguments `mirror` and `view` cannot be combined",
+            ));
+        }
+
+        errors.finish()?;
+
+        Ok(args)
+    }
+}
+
+/// Collects errors so that all invalid arguments are reported at once, each at its own span.
+#[derive(Default)]
+struct Errors(Option<Error>);
+
+impl Errors {
+    fn push(&mut self, error: Error) {
+        match &mut self.0 {
+            Some(errors) => errors.combine(error),
+            None => self.0 = Some(error),
+        }
+    }
+
+    fn finish(self) -> syn::Result<()> {
+        match self.0 {
+            Some(errors) => Err(errors),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Parses an argument that takes no value, e.g. `builder`.
+fn parse_flag(meta: &Meta, flag: &mut bool) -> syn::Result<()> {
+    match meta {
+        Meta::Path(path) if *flag => Err(duplicate_argument(path)),
+        Meta::Path(_) => {
+            *flag = true;
+
+            Ok(())
+        }
+        Meta::List(list) => Err(Error::new_spanned(
+            &list.nested,
+            format!(
+                "#[repr_std140] argument `{}` does not take a value",
+                argument_name(&list.path)
+            ),
+        )),
+        Meta::NameValue(name_value) => Err(Error::new_spanned(
+            &name_value.lit,
+            format!(
+                "#[repr_std140] argument `{}` does not take a value",
+                argument_name(&name_value.path)
+            ),
+        )),
+    }
+}
+
+/// Parses an argument that takes a type path in parentheses, e.g. `mirror(Light)`.
+fn parse_type_path(meta: &Meta, value: &mut Option<Path>) -> syn::Result<()> {
+    let list = match meta {
+        Meta::List(list) if value.is_some() => return Err(duplicate_argument(&list.path)),
+        Meta::List(list) => list,
+        meta => {
+            return Err(Error::new_spanned(
+                meta,
+                format!(
+                    "#[repr_std140] argument `{0}` expects a type, e.g. `{0}(Type)`",
+                    argument_name(meta.path())
+                ),
+            ))
+        }
+    };
+
+    match list.nested.iter().collect::<Vec<_>>().as_slice() {
+        [NestedMeta::Meta(Meta::Path(path))] => {
+            *value = Some(path.clone());
+
+            Ok(())
+        }
+        _ => Err(Error::new_spanned(
+            &list.nested,
+            format!(
+                "#[repr_std140] argument `{}` expects a single type",
+                argument_name(&list.path)
+            ),
+        )),
+    }
+}
+
+/// Parses an argument that takes an unsigned 32-bit integer, e.g. `binding = 2`.
+fn parse_u32(meta: &Meta, value: &mut Option<LitInt>) -> syn::Result<()> {
+    match meta {
+        Meta::NameValue(name_value) if value.is_some() => Err(duplicate_argument(&name_value.path)),
+        Meta::NameValue(name_value) => match &name_value.lit {
+            Lit::Int(lit) => {
+                let parsed = lit.base10_parse::<u32>()?;
+                *value = Some(LitInt::new(&parsed.to_string(), lit.span()));
+
+                Ok(())
+            }
+            lit => Err(Error::new_spanned(
+                lit,
+                format!(
+                    "#[repr_std140] argument `{}` expects an unsigned integer",
+                    argument_name(&name_value.path)
+                ),
+            )),
+        },
+        meta => Err(Error::new_spanned(
+            meta,
+            format!(
+                "#[repr_std140] argument `{0}` expects a value, e.g. `{0} = 0`",
+                argument_name(meta.path())
+            ),
+        )),
+    }
+}
+
+fn duplicate_argument(path: &Path) -> Error {
+    Error::new_spanned(
+        path,
+        format!(
+            "duplicate #[repr_std140] argument `{}`",
+            argument_name(path)
+        ),
+    )
+}
+
+fn argument_name(path: &Path) -> String {
+    path.get_ident()
+        .map(|ident| ident.to_string())
+        .unwrap_or_else(|| quote::quote!(#path).to_string())
+}
+
+/// Reports a trait listed in a `derive(...)` argument at its own span, e.g. at `Cop` in
+/// `derive(Cop)`, pointing to the `#[derive]` attribute instead.
+fn derive_argument(nested: &NestedMeta) -> Error {
+    let name = match nested {
+        NestedMeta::Meta(meta) => argument_name(meta.path()),
+        NestedMeta::Lit(lit) => quote::quote!(#lit).to_string(),
+    };
+
+    Error::new_spanned(
+        nested,
+        format!(
+            "unknown #[repr_std140] argument `derive({})`, derive traits with `#[derive({})]` on the struct instead",
+            name, name
+        ),
+    )
+}
+
+fn unknown_argument(meta: &Meta) -> Error {
+    let path = meta.path();
+
+    Error::new_spanned(
+        path,
+        format!("unknown #[repr_std140] argument `{}`", argument_name(path)),
+    )
+}
@@ -0,0 +1,42 @@
+use ::{
+    quote::ToTokens,
+    syn::{Error, Expr, GenericArgument, Lit, Type},
+};
+
+/// Rejects field types that can't be declared in a GLSL uniform block, which are unbounded arrays:
+/// uniform blocks can't contain runtime-sized arrays.
+///
+/// The names of all other types are taken from their `GlslType` implementation by the generated
+/// code.
+pub fn check_uniform_member(ty: &Type) -> Result<(), Error> {
+    let path = match ty {
+        Type::Group(group) => return check_uniform_member(&group.elem),
+        Type::Paren(paren) => return check_uniform_member(&paren.elem),
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        _ => return Ok(()),
+    };
+
+    match path.segments.last() {
+        Some(segment) if segment.ident == "unbounded_array" => Err(Error::new_spanned(
+            segment,
+            "`unbounded_array` cannot be declared in a GLSL uniform block, as runtime-sized arrays \
+             are only allowed as the last member of a shader storage block",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Returns the array length as written in GLSL, which is the literal value for literal lengths
+/// and the length expression itself otherwise.
+pub fn array_len(len: &GenericArgument) -> String {
+    match len {
+        GenericArgument::Const(Expr::Lit(lit)) => match &lit.lit {
+            Lit::Int(int) => int.base10_digits().to_owned(),
+            lit => lit.to_token_stream().to_string(),
+        },
+        GenericArgument::Const(Expr::Block(block)) if block.block.stmts.len() == 1 => {
+            block.block.stmts[0].to_token_stream().to_string()
+        }
+        len => len.to_token_stream().to_string(),
+    }
+}
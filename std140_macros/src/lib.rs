@@ -1,24 +1,19 @@
 use ::{
     proc_macro::TokenStream,
-    quote::quote,
     syn::{parse_macro_input, DeriveInput},
 };
 
+mod args;
+mod glsl;
 mod repr_std140;
+mod wgsl;
 
 #[proc_macro_attribute]
 pub fn repr_std140(args: TokenStream, input: TokenStream) -> TokenStream {
-    assert!(args.is_empty(), "#[repr_std140] does not take arguments.");
-
+    let args = parse_macro_input!(args as args::ReprStd140Args);
     let input = parse_macro_input!(input as DeriveInput);
 
-    repr_std140::expand_repr_std140(&input)
-        .unwrap_or_else(compile_error)
+    repr_std140::expand_repr_std140(&args, &input)
+        .unwrap_or_else(syn::Error::into_compile_error)
         .into()
 }
-
-fn compile_error(message: String) -> proc_macro2::TokenStream {
-    quote! {
-        compile_error!(#message);
-    }
-}
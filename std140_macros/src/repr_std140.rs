@@ -1,73 +1,587 @@
 use ::{
     proc_macro2::{Span, TokenStream},
-    quote::{quote, quote_spanned},
-    syn::{spanned::Spanned, Data, DeriveInput, Ident},
+    quote::{format_ident, quote, quote_spanned},
+    syn::{
+        parse_quote, spanned::Spanned, Attribute, Data, DeriveInput, Error, Expr, Field, Fields,
+        Ident, Index, Member, Path, Type,
+    },
 };
 
-pub fn expand_repr_std140(input: &DeriveInput) -> Result<TokenStream, String> {
-    if let Data::Struct(data) = &input.data {
-        if has_other_repr(input) {
-            return Err(
-                "Cannot parse another #[repr] attribute on a struct marked with #[repr_std140]"
-                    .to_string(),
-            );
+use crate::{args::ReprStd140Args, glsl, wgsl};
+
+pub fn expand_repr_std140(
+    args: &ReprStd140Args,
+    input: &DeriveInput,
+) -> Result<TokenStream, Error> {
+    let mut input = input.clone();
+    if args.wrap_arrays {
+        if let Data::Struct(data) = &mut input.data {
+            for field in data.fields.iter_mut() {
+                wrap_array(&mut field.ty);
+            }
         }
+    }
+    let input = &input;
 
-        let mod_path = quote!(std140);
-        let struct_name = &input.ident;
-        let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let data = match &input.data {
+        Data::Struct(data) => data,
+        Data::Enum(data) => {
+            return Err(Error::new(
+                data.enum_token.span,
+                "Cannot represent an enum or union as std140, only a struct.",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span,
+                "Cannot represent an enum or union as std140, only a struct.",
+            ))
+        }
+    };
+
+    if let Some(attr) = other_repr(input) {
+        return Err(Error::new_spanned(
+            attr,
+            "Cannot parse another #[repr] attribute on a struct marked with #[repr_std140]",
+        ));
+    }
 
-        let asserts = data.fields.iter().map(|field| {
+    if data.fields.is_empty() {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "Cannot represent a struct without fields as std140, add at least one field",
+        ));
+    }
+
+    for field in &data.fields {
+        if let Some(message) = unsupported_field_type(&field.ty) {
+            return Err(Error::new_spanned(&field.ty, message));
+        }
+    }
+
+    let mod_path = quote!(std140);
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // Field types are bounded on the `Std140Struct` impl rather than checked within its items, so a
+    // field type that is not an std140 type is reported once instead of once per generated item.
+    // Where clauses cannot carry `#[cfg]` attributes, so fields behind one are asserted separately.
+    let mut std140_where_clause = where_clause.cloned().unwrap_or_else(|| parse_quote!(where));
+    let mut cfg_fields = Vec::new();
+    for field in &data.fields {
+        if cfg_attrs(field).next().is_some() {
+            cfg_fields.push(field);
+            continue;
+        }
+
+        let ty = &field.ty;
+        let bound = respan(quote!(#mod_path::ReprStd140), ty.span());
+        std140_where_clause
+            .predicates
+            .push(parse_quote!(#ty: #bound));
+    }
+
+    let asserts = if cfg_fields.is_empty() {
+        quote!()
+    } else {
+        let asserts = cfg_fields.iter().map(|field| {
             let ty = &field.ty;
             let span = field.span();
+            let cfgs = cfg_attrs(field);
 
-            quote_spanned!(span=> assert_repr_std140::<#ty> { marker: std::marker::PhantomData };)
+            quote_spanned!(span=> #(#cfgs)* assert_repr_std140::<#ty> { marker: std::marker::PhantomData };)
         });
 
-        let suffix = struct_name.to_string().trim_start_matches("r#").to_owned();
-        let dummy_const = Ident::new(
-            &format!("_IMPL_REPR_STD140_FOR_{}", suffix),
-            Span::call_site(),
-        );
-
-        let asserts = quote! {
+        quote! {
             struct assert_repr_std140<T> where T: #mod_path::ReprStd140 {
                 marker: std::marker::PhantomData<T>
             }
 
             #(#asserts)*
-        };
+        }
+    };
+
+    let members = data.fields.iter().enumerate().map(|(i, field)| {
+        let ty = &field.ty;
+        let (member, name) = member_and_name(i, field);
+        let cfgs = cfg_attrs(field);
+
+        quote! {
+            #(#cfgs)*
+            #mod_path::layout::Member {
+                name: #name,
+                offset: ::core::mem::offset_of!(Self, #member),
+                size: ::core::mem::size_of::<#ty>(),
+                align: ::core::mem::align_of::<#ty>(),
+                padding: <#ty as #mod_path::ReprStd140>::STD140_PADDING,
+                kind: <#ty as #mod_path::ReprStd140>::STD140_KIND,
+                glsl_type: <#ty as #mod_path::glsl::GlslType>::GLSL_NAME,
+            }
+        }
+    });
+
+    let field_writes = data.fields.iter().enumerate().map(|(i, field)| {
+        let ty = &field.ty;
+        let (member, _) = member_and_name(i, field);
+        let cfgs = cfg_attrs(field);
+
+        quote! {
+            #(#cfgs)*
+            <#ty as #mod_path::ReprStd140>::write_std140_bytes(
+                &self.#member,
+                &mut bytes[::core::mem::offset_of!(Self, #member)..][..::core::mem::size_of::<#ty>()],
+                order,
+            );
+        }
+    });
+
+    let struct_name_str = struct_name.to_string();
+    let struct_name_str = struct_name_str.trim_start_matches("r#");
+
+    let impl_std140_struct = quote! {
+        #[automatically_derived]
+        unsafe impl #impl_generics #mod_path::Std140Struct for #struct_name #ty_generics #std140_where_clause {
+            const STD140_LAYOUT: #mod_path::layout::Layout = #mod_path::layout::Layout {
+                size: ::core::mem::size_of::<Self>(),
+                align: ::core::mem::align_of::<Self>(),
+                members: &[#(#members),*],
+            };
 
-        let impl_std140_struct = quote! {
+            const STRUCT_NAME: &'static str = #struct_name_str;
+
+            fn write_std140_fields(&self, bytes: &mut [u8], order: #mod_path::ByteOrder) {
+                #(#field_writes)*
+            }
+        }
+    };
+
+    let as_ref = if args.as_ref {
+        quote! {
             #[automatically_derived]
-            unsafe impl #impl_generics #mod_path::Std140Struct for #struct_name #ty_generics #where_clause {}
-        };
+            impl #impl_generics ::core::convert::AsRef<[u8]> for #struct_name #ty_generics #where_clause {
+                fn as_ref(&self) -> &[u8] {
+                    const {
+                        ::core::assert!(
+                            <Self as #mod_path::ReprStd140>::STD140_PADDING == 0,
+                            "#[repr_std140(as_ref)] requires a struct without padding",
+                        )
+                    };
+
+                    // SAFETY: the pointer is derived from a valid reference, the length is the size
+                    // of `Self` and the returned slice borrows from `self`. The struct has no
+                    // padding, so every byte is initialized.
+                    unsafe {
+                        ::core::slice::from_raw_parts(
+                            self as *const Self as *const u8,
+                            ::core::mem::size_of::<Self>(),
+                        )
+                    }
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let builder = if args.builder {
+        let setters = data.fields.iter().enumerate().map(|(i, field)| {
+            let ty = &field.ty;
+            let vis = &field.vis;
+            let (member, name) = member_and_name(i, field);
+            let setter = format_ident!("with_{}", name);
+            let doc = format!("Sets `{}` to `value` and returns the updated struct.", name);
+            let cfgs = cfg_attrs(field);
+
+            quote! {
+                #(#cfgs)*
+                #[doc = #doc]
+                #[must_use]
+                #vis fn #setter(mut self, value: #ty) -> Self {
+                    self.#member = value;
+                    self
+                }
+            }
+        });
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #struct_name #ty_generics #where_clause {
+                #(#setters)*
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let glsl_decl = if args.glsl {
+        let header = format!("layout(std140) uniform {} {{\n", struct_name_str);
+        let lines = data
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                glsl::check_uniform_member(&field.ty)?;
+
+                let ty = &field.ty;
+                let name = match &field.ident {
+                    Some(_) => member_and_name(i, field).1,
+                    None => format!("_{}", i),
+                };
+                let cfgs: Vec<_> = cfg_attrs(field).collect();
+                let glsl_name = quote!(<#ty as #mod_path::glsl::GlslType>::GLSL_NAME);
+
+                // GLSL declares arrays with the dimensions after the member name, e.g.
+                // `PointLight lights[2]` for a member of type `PointLight[2]`.
+                Ok(quote! {
+                    #(#cfgs)* "    ",
+                    #(#cfgs)* #mod_path::glsl::split_array_dims(#glsl_name).0,
+                    #(#cfgs)* " ",
+                    #(#cfgs)* #name,
+                    #(#cfgs)* #mod_path::glsl::split_array_dims(#glsl_name).1,
+                    #(#cfgs)* ";\n"
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let vis = &input.vis;
+
+        // The declaration is concatenated at compile time from the pieces of one line per field,
+        // such that the lines of fields removed by `#[cfg]` evaluation are left out.
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #struct_name #ty_generics #where_clause {
+                /// The GLSL declaration of a uniform block matching the layout of this struct.
+                #vis const GLSL_DECL: &'static str = {
+                    const LINES: &[&str] = &[#header, #(#lines,)* "};\n"];
+                    const LEN: usize = #mod_path::glsl::concat_len(LINES);
+                    const DECL: [u8; LEN] = #mod_path::glsl::concat(LINES);
+
+                    #mod_path::glsl::from_utf8(&DECL)
+                };
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let wgsl_definition = if args.wgsl {
+        let fields = data
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let ty = wgsl::wgsl_type(&field.ty)?;
+                let name = match &field.ident {
+                    Some(_) => member_and_name(i, field).1,
+                    None => format!("_{}", i),
+                };
+                let cfgs = cfg_attrs(field);
 
-        let generated = quote! {
-            #[repr(C, align(16))]
-            #input
+                Ok(quote!(#(#cfgs)* (#name, #ty)))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
 
-            #[allow(non_upper_case_globals, unused_attributes, unused_qualifications)]
-            const #dummy_const: () = {
-                #[allow(unknown_lints)]
-                #[cfg_attr(feature = "cargo-clippy", allow(useless_attribute))]
-                #[allow(rust_2018_idioms)]
+        let vis = &input.vis;
+
+        // WGSL lays out some types more tightly than std140, e.g. `vec3<f32>` takes 12 rather
+        // than 16 bytes, so every member spells out the alignment and size of its std140 slot.
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #struct_name #ty_generics #where_clause {
+                /// Returns the WGSL definition of a struct matching the layout of this struct.
+                #vis fn wgsl_definition() -> ::std::string::String {
+                    use ::std::fmt::Write as _;
 
-                #asserts
+                    const FIELDS: &[(&str, &str)] = &[#(#fields),*];
+                    let layout = <Self as #mod_path::Std140Struct>::STD140_LAYOUT;
 
-                #impl_std140_struct
+                    let mut definition = ::std::format!("struct {} {{\n", #struct_name_str);
+                    for (i, ((name, ty), member)) in FIELDS.iter().zip(layout.members).enumerate() {
+                        let end = layout
+                            .members
+                            .get(i + 1)
+                            .map_or(layout.size, |next| next.offset);
+
+                        ::std::writeln!(
+                            definition,
+                            "    @align({}) @size({}) {}: {},",
+                            member.align,
+                            end - member.offset,
+                            name,
+                            ty,
+                        )
+                        .unwrap();
+                    }
+                    definition.push_str("}\n");
+
+                    definition
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let padding_report = if args.warn_padding {
+        let vis = &input.vis;
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #struct_name #ty_generics #where_clause {
+                /// A report of the padding in the std140 layout of this struct.
+                #vis const PADDING_REPORT: #mod_path::layout::PaddingReport =
+                    #mod_path::layout::PaddingReport::new(
+                        #struct_name_str,
+                        <Self as #mod_path::Std140Struct>::STD140_LAYOUT,
+                    );
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let view = match &args.view {
+        Some(view) => {
+            if let Some(param) = input.generics.type_params().next() {
+                return Err(Error::new_spanned(
+                    param,
+                    "#[repr_std140] argument `view` does not support generic structs, declare the plain struct yourself and use `mirror(Type)`",
+                ));
+            }
+
+            let vis = &input.vis;
+            let doc = format!(
+                "The plain counterpart of [`{}`], generated by `#[repr_std140(view = {})]`.",
+                struct_name, view
+            );
+            let fields = data.fields.iter().map(|field| {
+                let ty = &field.ty;
+                let vis = &field.vis;
+                let cfgs = cfg_attrs(field);
+                let span = field.span();
+
+                match &field.ident {
+                    Some(ident) => quote_spanned! {span=>
+                        #(#cfgs)*
+                        #vis #ident: <#ty as #mod_path::plain::PlainRepr>::Plain
+                    },
+                    None => quote_spanned! {span=>
+                        #(#cfgs)*
+                        #vis <#ty as #mod_path::plain::PlainRepr>::Plain
+                    },
+                }
+            });
+            let body = match &data.fields {
+                Fields::Named(_) => quote!({ #(#fields),* }),
+                _ => quote!(( #(#fields),* );),
             };
-        };
 
-        Ok(generated)
+            quote! {
+                #[doc = #doc]
+                #[derive(Clone, Copy, PartialEq, Debug)]
+                #vis struct #view #body
+            }
+        }
+        None => quote!(),
+    };
+
+    let mirror = args
+        .mirror
+        .clone()
+        .or_else(|| args.view.clone().map(Path::from));
+    let mirror = match &mirror {
+        Some(mirror) => {
+            let fields = data.fields.iter().enumerate().map(|(i, field)| {
+                let ty = &field.ty;
+                let (member, _) = member_and_name(i, field);
+                let cfgs: Vec<_> = cfg_attrs(field).collect();
+                let span = field.span();
+
+                (
+                    quote_spanned! {span=>
+                        #(#cfgs)*
+                        #member: <#ty as #mod_path::plain::PlainRepr>::into_plain(value.#member)
+                    },
+                    quote_spanned! {span=>
+                        #(#cfgs)*
+                        #member: <#ty as #mod_path::plain::PlainRepr>::from_plain(value.#member)
+                    },
+                )
+            });
+            let (into_mirror, from_mirror): (Vec<_>, Vec<_>) = fields.unzip();
+
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics ::core::convert::From<#struct_name #ty_generics> for #mirror #where_clause {
+                    fn from(value: #struct_name #ty_generics) -> Self {
+                        Self { #(#into_mirror),* }
+                    }
+                }
+
+                #[automatically_derived]
+                impl #impl_generics ::core::convert::From<#mirror> for #struct_name #ty_generics #where_clause {
+                    fn from(value: #mirror) -> Self {
+                        Self { #(#from_mirror),* }
+                    }
+                }
+
+                #[automatically_derived]
+                impl #impl_generics #mod_path::plain::PlainRepr for #struct_name #ty_generics #where_clause {
+                    type Plain = #mirror;
+
+                    fn into_plain(self) -> Self::Plain {
+                        ::core::convert::From::from(self)
+                    }
+
+                    fn from_plain(plain: Self::Plain) -> Self {
+                        ::core::convert::From::from(plain)
+                    }
+                }
+            }
+        }
+        None => quote!(),
+    };
+
+    let vis = &input.vis;
+    let binding = args.binding.as_ref().map(|binding| {
+        quote! {
+            /// The binding point of the uniform block matching this struct.
+            #vis const BINDING: u32 = #binding;
+        }
+    });
+    let set = args.set.as_ref().map(|set| {
+        quote! {
+            /// The descriptor set of the uniform block matching this struct.
+            #vis const SET: u32 = #set;
+        }
+    });
+    let binding_metadata = if binding.is_some() || set.is_some() {
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #struct_name #ty_generics #where_clause {
+                #binding
+                #set
+            }
+        }
     } else {
-        Err("Cannot represent an enum or union as std140, only a struct.".to_string())
+        quote!()
+    };
+
+    let generated = quote! {
+        #[repr(C, align(16))]
+        #input
+
+        #view
+
+        #[allow(non_upper_case_globals, unused_attributes, unused_qualifications)]
+        #[allow(clippy::no_effect)]
+        const _: () = {
+            #[allow(unknown_lints)]
+            #[allow(clippy::useless_attribute)]
+            #[allow(rust_2018_idioms)]
+
+            #asserts
+
+            #impl_std140_struct
+
+            #as_ref
+
+            #builder
+
+            #glsl_decl
+
+            #wgsl_definition
+
+            #padding_report
+
+            #mirror
+
+            #binding_metadata
+        };
+    };
+
+    Ok(generated)
+}
+
+/// Returns the member used to access the field at `index` and the field's name, which for tuple
+/// structs is the field's index.
+fn member_and_name(index: usize, field: &Field) -> (Member, String) {
+    match &field.ident {
+        Some(ident) => (
+            Member::Named(ident.clone()),
+            ident.to_string().trim_start_matches("r#").to_owned(),
+        ),
+        None => (Member::Unnamed(Index::from(index)), index.to_string()),
+    }
+}
+
+/// Rewrites the Rust array type `[T; N]` to the std140 array type `array<T, N>`, including the
+/// arrays nested in `T`.
+fn wrap_array(ty: &mut Type) {
+    if let Type::Array(array) = ty {
+        wrap_array(&mut array.elem);
+
+        let elem = &array.elem;
+        let len = &array.len;
+        *ty = match len {
+            Expr::Lit(_) | Expr::Path(_) => parse_quote!(std140::array::array<#elem, #len>),
+            _ => parse_quote!(std140::array::array<#elem, { #len }>),
+        };
+    }
+}
+
+/// Returns a tailored error message for common field types that can never be represented as
+/// std140, which would otherwise only be reported as an unsatisfied `ReprStd140` bound.
+///
+/// Like the GLSL type mapping, this is purely syntactic and only looks at the last path segment.
+fn unsupported_field_type(ty: &Type) -> Option<&'static str> {
+    let path = match ty {
+        Type::Group(group) => return unsupported_field_type(&group.elem),
+        Type::Paren(paren) => return unsupported_field_type(&paren.elem),
+        Type::Reference(_) | Type::Ptr(_) => {
+            return Some(
+                "Cannot represent a reference or pointer as std140, which stores all values inline; store the value itself",
+            )
+        }
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        _ => return None,
+    };
+
+    match path.segments.last()?.ident.to_string().as_str() {
+        "Option" => Some(
+            "Cannot represent an `Option` as std140, which has no optional values; use a sentinel value or add a separate `std140::boolean` flag field",
+        ),
+        "Vec" => Some(
+            "Cannot represent a `Vec` as std140, which has no growable arrays; use `std140::array::array<T, N>` with a fixed length",
+        ),
+        "Box" => Some(
+            "Cannot represent a `Box` as std140, which stores all values inline; store the value itself",
+        ),
+        _ => None,
     }
 }
 
-fn has_other_repr(input: &DeriveInput) -> bool {
+/// Returns the `#[cfg]` attributes of `field`, which must be repeated on every item generated for
+/// the field so that fields removed by cfg evaluation are skipped.
+/// Sets the span of the top-level tokens in `tokens` to `span`, so that errors about them point at
+/// `span`.
+fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|mut token| {
+            token.set_span(span);
+            token
+        })
+        .collect()
+}
+
+fn cfg_attrs(field: &Field) -> impl Iterator<Item = &Attribute> {
+    field.attrs.iter().filter(|attr| attr.path.is_ident("cfg"))
+}
+
+fn other_repr(input: &DeriveInput) -> Option<&Attribute> {
     input
         .attrs
         .iter()
-        .any(|attr| attr.path.is_ident(&Ident::new("repr", Span::call_site())))
+        .find(|attr| attr.path.is_ident(&Ident::new("repr", Span::call_site())))
 }
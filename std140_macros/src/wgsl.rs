@@ -0,0 +1,123 @@
+use ::syn::{Error, GenericArgument, PathArguments, Type};
+
+use crate::glsl;
+
+/// Maps a field type to its WGSL type name, e.g. `array<vec4<f32>, 2>` for
+/// `std140::array<vec4, 2>`.
+///
+/// The mapping is purely syntactic: the last path segment of the type names the WGSL type, and any
+/// unknown name, such as that of a struct marked with `#[repr_std140]`, is used as is. Types whose WGSL counterpart is laid out
+/// differently are rejected: matrices with 2 rows, whose WGSL columns are 8 rather than 16 bytes
+/// apart, and arrays of elements smaller than 16 bytes, whose WGSL stride isn't rounded up to 16.
+pub fn wgsl_type(ty: &Type) -> Result<String, Error> {
+    let path =
+        match ty {
+            Type::Group(group) => return wgsl_type(&group.elem),
+            Type::Paren(paren) => return wgsl_type(&paren.elem),
+            Type::Path(path) if path.qself.is_none() => &path.path,
+            _ => return Err(Error::new_spanned(
+                ty,
+                "Cannot map this type to a WGSL type, use a std140 type or a #[repr_std140] struct",
+            )),
+        };
+
+    let segment = path
+        .segments
+        .last()
+        .expect("a type path has at least one segment");
+    let name = segment.ident.to_string();
+
+    match name.as_str() {
+        "array" | "unbounded_array" => {
+            let args = match &segment.arguments {
+                PathArguments::AngleBracketed(args) => &args.args,
+                _ => {
+                    return Err(Error::new_spanned(
+                        segment,
+                        "Expected the array element type",
+                    ))
+                }
+            };
+
+            let element = args.iter().find_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            });
+            let element = match element {
+                Some(element) => element,
+                None => return Err(Error::new_spanned(args, "Expected the array element type")),
+            };
+
+            if let Some(element_name) = unaligned_element(element) {
+                return Err(Error::new_spanned(
+                    element,
+                    format!(
+                        "WGSL arrays of `{}` are not strided by 16 bytes like std140 arrays, use \
+                         an element type of at least 16 bytes such as a vec4",
+                        element_name
+                    ),
+                ));
+            }
+
+            let element = wgsl_type(element)?;
+
+            match args.iter().nth(1) {
+                Some(len) => Ok(format!("array<{}, {}>", element, glsl::array_len(len))),
+                None => Ok(format!("array<{}>", element)),
+            }
+        }
+        "mat2x2" | "mat3x2" | "mat4x2" => Err(Error::new_spanned(
+            ty,
+            format!(
+                "the columns of the WGSL `{}<f32>` are not 16 bytes apart like in std140, use a \
+                 matrix with more rows",
+                name
+            ),
+        )),
+        _ => Ok(wgsl_name(&name).to_owned()),
+    }
+}
+
+/// Returns the WGSL name for the std140 type `name`, or `name` itself if it isn't an std140 type.
+///
+/// WGSL booleans can't be stored in buffers, so std140 booleans map to the `u32` they are made of.
+fn wgsl_name(name: &str) -> &str {
+    match name {
+        "float" => "f32",
+        "int" => "i32",
+        "uint" | "boolean" | "packed_rgba8" => "u32",
+        "vec2" => "vec2<f32>",
+        "vec3" => "vec3<f32>",
+        "vec4" => "vec4<f32>",
+        "ivec2" => "vec2<i32>",
+        "ivec3" => "vec3<i32>",
+        "ivec4" => "vec4<i32>",
+        "uvec2" | "bvec2" => "vec2<u32>",
+        "uvec3" | "bvec3" => "vec3<u32>",
+        "uvec4" | "bvec4" => "vec4<u32>",
+        "mat2x3" => "mat2x3<f32>",
+        "mat2x4" => "mat2x4<f32>",
+        "mat3x3" => "mat3x3<f32>",
+        "mat3x4" => "mat3x4<f32>",
+        "mat4x3" => "mat4x3<f32>",
+        "mat4x4" => "mat4x4<f32>",
+        _ => name,
+    }
+}
+
+/// Returns the name of `element` if it is an std140 type smaller than 16 bytes, which WGSL arrays
+/// pack more tightly than std140 arrays.
+fn unaligned_element(element: &Type) -> Option<String> {
+    let name = match element {
+        Type::Group(group) => return unaligned_element(&group.elem),
+        Type::Paren(paren) => return unaligned_element(&paren.elem),
+        Type::Path(path) => path.path.segments.last()?.ident.to_string(),
+        _ => return None,
+    };
+
+    match name.as_str() {
+        "float" | "int" | "uint" | "boolean" | "packed_rgba8" | "vec2" | "ivec2" | "uvec2"
+        | "bvec2" => Some(name),
+        _ => None,
+    }
+}
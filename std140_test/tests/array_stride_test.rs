@@ -0,0 +1,25 @@
+use std::mem::size_of;
+use std140::{array::array, Std140Struct};
+
+#[std140::repr_std140]
+struct Particle {
+    position: std140::vec::vec3,
+    velocity: std140::vec::vec3,
+    lifetime: std140::float,
+}
+
+#[std140::repr_std140]
+struct Exposure(std140::float);
+
+#[test]
+fn array_stride_test() {
+    assert_eq!(Particle::STD140_ARRAY_STRIDE, 48);
+    assert_eq!(Particle::STD140_ARRAY_STRIDE, size_of::<Particle>());
+    assert_eq!(
+        Particle::STD140_ARRAY_STRIDE * 3,
+        size_of::<array<Particle, 3>>()
+    );
+
+    assert_eq!(Exposure::STD140_ARRAY_STRIDE, 16);
+    assert_eq!(Exposure::STD140_ARRAY_STRIDE, size_of::<Exposure>());
+}
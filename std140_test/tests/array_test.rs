@@ -0,0 +1,203 @@
+use std140::{array::array, uint};
+
+#[test]
+fn array_of_arrays_layout_test() {
+    assert_eq!(std::mem::size_of::<array<array<uint, 3>, 2>>(), 96);
+    assert_eq!(std::mem::align_of::<array<array<uint, 3>, 2>>(), 16);
+}
+
+#[test]
+fn array_flatten_to_vec_test() {
+    let table: array<array<uint, 2>, 2> = std140::array![
+        std140::array![uint(0), uint(1)],
+        std140::array![uint(2), uint(3)],
+    ];
+
+    let flat = table.flatten_to_vec();
+
+    assert_eq!(flat.len(), 4);
+
+    for (i, element) in flat.iter().enumerate() {
+        assert_eq!(element.0, uint(i as u32));
+    }
+}
+
+#[test]
+fn array_into_iter_test() {
+    let values: array<uint, 3> = std140::array![uint(1), uint(2), uint(3)];
+
+    let borrowed: Vec<&uint> = (&values).into_iter().collect();
+    assert_eq!(borrowed, vec![&uint(1), &uint(2), &uint(3)]);
+
+    let owned: Vec<uint> = values.into_iter().collect();
+    assert_eq!(owned, vec![uint(1), uint(2), uint(3)]);
+}
+
+fn splat<const N: usize>(value: std140::float) -> array<std140::float, N> {
+    std140::array![value; N]
+}
+
+fn indices<const N: usize>() -> array<uint, N> {
+    array::from_fn(|i| uint(i as u32))
+}
+
+#[test]
+fn array_generic_len_test() {
+    let values = splat::<3>(std140::float(0.5));
+
+    assert_eq!(values.len(), 3);
+    assert!(values.iter().all(|value| value.0 == std140::float(0.5)));
+
+    assert_eq!(
+        indices::<4>(),
+        std140::array![uint(0), uint(1), uint(2), uint(3)]
+    );
+}
+
+#[test]
+fn array_from_fn_non_copy_test() {
+    #[std140::repr_std140]
+    #[derive(Clone, PartialEq, Debug)]
+    struct Light {
+        intensity: std140::float,
+    }
+
+    let lights: array<Light, 2> = array::from_fn(|i| Light {
+        intensity: std140::float(i as f32),
+    });
+
+    assert_eq!(
+        lights.last().map(|light| light.intensity),
+        Some(std140::float(1.0))
+    );
+}
+
+#[std140::repr_std140]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct PointLight {
+    position: std140::vec::vec3,
+    intensity: std140::float,
+}
+
+#[test]
+fn array_from_parallel_slices_test() {
+    let positions = [
+        std140::vec::vec3(0.0, 0.0, 0.0),
+        std140::vec::vec3(1.0, 2.0, 3.0),
+        std140::vec::vec3(4.0, 5.0, 6.0),
+    ];
+    let intensities = [std140::float(0.25), std140::float(0.5), std140::float(1.0)];
+
+    let lights: array<PointLight, 3> = array::from_fn(|i| PointLight {
+        position: positions[i],
+        intensity: intensities[i],
+    });
+
+    for (i, light) in lights.iter().enumerate() {
+        assert_eq!(light.0.position, positions[i]);
+        assert_eq!(light.0.intensity, intensities[i]);
+    }
+}
+
+#[test]
+fn array_try_from_vec_test() {
+    let values: array<uint, 3> = vec![uint(1), uint(2), uint(3)].try_into().unwrap();
+    assert_eq!(values, std140::array![uint(1), uint(2), uint(3)]);
+
+    let too_short = array::<uint, 3>::try_from(vec![uint(1), uint(2)]);
+    assert_eq!(too_short.unwrap_err(), vec![uint(1), uint(2)]);
+
+    let too_long = array::<uint, 1>::try_from(vec![uint(1), uint(2)]);
+    assert_eq!(too_long.unwrap_err().len(), 2);
+}
+
+#[std140::repr_std140]
+#[derive(PartialEq, Debug)]
+struct Material {
+    color: std140::vec::vec4,
+}
+
+#[test]
+fn array_try_from_vec_moves_test() {
+    let materials = vec![
+        Material {
+            color: std140::vec::vec4(1.0, 0.0, 0.0, 1.0),
+        },
+        Material {
+            color: std140::vec::vec4(0.0, 1.0, 0.0, 1.0),
+        },
+    ];
+
+    let materials: array<Material, 2> = materials.try_into().unwrap();
+
+    assert_eq!(materials[1].0.color, std140::vec::vec4(0.0, 1.0, 0.0, 1.0));
+}
+
+#[test]
+fn array_contains_position_test() {
+    let ids: array<uint, 3> = std140::array![uint(1), uint(5), uint(5)];
+
+    assert!(ids.contains(&uint(5)));
+    assert!(!ids.contains(&uint(4)));
+    assert_eq!(ids.position(|id| *id == uint(5)), Some(1));
+    assert_eq!(ids.position(|id| id.0 > 5), None);
+}
+
+#[test]
+fn alignmented_element_new_test() {
+    use std140::array::AlignmentedElement;
+
+    let element = AlignmentedElement::new(uint(3));
+    assert_eq!(element.0, uint(3));
+    assert_eq!(AlignmentedElement::from(uint(3)), element);
+
+    let values: array<uint, 2> = array::new([AlignmentedElement::new(uint(3)), uint(4).into()]);
+    assert_eq!(values, std140::array![uint(3), uint(4)]);
+}
+
+#[test]
+fn array_filled_test() {
+    #[std140::repr_std140]
+    #[derive(Clone, PartialEq, Debug)]
+    struct Fog {
+        color: std140::vec::vec4,
+        density: std140::float,
+    }
+
+    let fog = Fog {
+        color: std140::vec::vec4(0.5, 0.5, 0.5, 1.0),
+        density: std140::float(0.1),
+    };
+
+    let layers: array<Fog, 4> = array::filled(fog.clone());
+
+    assert_eq!(layers.len(), 4);
+    assert!(layers.iter().all(|layer| layer.0 == fog));
+
+    let ids: array<uint, 2> = array::filled(uint(7));
+    assert_eq!(ids, std140::array![uint(7); 2]);
+}
+
+#[test]
+fn array_repeat_const_test() {
+    const DEFAULTS: array<uint, 4> = array::repeat(uint(0));
+    static ONES: array<std140::vec::vec2, 2> = array::repeat(std140::vec::vec2(1.0, 1.0));
+
+    assert_eq!(DEFAULTS, std140::array![uint(0); 4]);
+    assert!(ONES.iter().all(|one| one.0 == std140::vec::vec2(1.0, 1.0)));
+}
+
+#[test]
+fn array_split_at_test() {
+    let bones: array<std140::mat::mat4x4, 8> =
+        array::from_fn(|i| std140::mat::mat4x4::identity() * i as f32);
+    let (first, second) = bones.split_at(4);
+
+    assert_eq!(first.len(), 4);
+    assert_eq!(second.len(), 4);
+    assert_eq!(*second[0], std140::mat::mat4x4::identity() * 4.0);
+    assert_eq!(
+        second.as_ptr() as usize - first.as_ptr() as usize,
+        4 * std::mem::size_of::<std140::mat::mat4x4>()
+    );
+}
@@ -0,0 +1,77 @@
+use std140::Std140Struct;
+
+#[std140::repr_std140]
+struct Uniforms {
+    light_count: std140::uint,
+    color: std140::vec::vec4,
+}
+
+#[test]
+fn std140_struct_to_std140_bytes_test() {
+    let uniforms = Uniforms {
+        light_count: std140::uint(3),
+        color: std140::vec::vec4(0.25, 0.5, 0.75, 1.0),
+    };
+
+    let bytes = uniforms.to_std140_bytes();
+
+    assert_eq!(bytes.len(), 32);
+    assert_eq!(bytes[..4], 3u32.to_ne_bytes());
+    assert_eq!(bytes[4..16], [0; 12]);
+    assert_eq!(bytes[16..20], 0.25f32.to_ne_bytes());
+    assert_eq!(bytes[28..32], 1.0f32.to_ne_bytes());
+}
+
+#[test]
+fn std140_struct_as_ptr_alignment_test() {
+    // Structs boxed, in a `Vec` or on the stack are all aligned to 16 bytes.
+    let boxed = Box::new(Uniforms {
+        light_count: std140::uint(1),
+        color: std140::vec::vec4(1.0, 1.0, 1.0, 1.0),
+    });
+    let many: Vec<Uniforms> = (0..3)
+        .map(|i| Uniforms {
+            light_count: std140::uint(i),
+            color: std140::vec::vec4(0.0, 0.0, 0.0, 0.0),
+        })
+        .collect();
+
+    assert_eq!(boxed.as_ptr(), &*boxed as *const Uniforms as *const u8);
+    assert_eq!(boxed.as_ptr() as usize % 16, 0);
+
+    for uniforms in &many {
+        assert_eq!(uniforms.as_ptr() as usize % 16, 0);
+    }
+}
+
+#[test]
+fn as_std140_bytes_test() {
+    use std140::AsStd140Bytes;
+
+    fn bytes(data: &impl AsStd140Bytes) -> Vec<u8> {
+        data.std140_bytes()
+    }
+
+    let uniforms = Uniforms {
+        light_count: std140::uint(3),
+        color: std140::vec::vec4(0.25, 0.5, 0.75, 1.0),
+    };
+    assert_eq!(bytes(&uniforms), uniforms.to_std140_bytes());
+
+    let color = std140::vec::vec4(0.25, 0.5, 0.75, 1.0);
+    assert_eq!(bytes(&color).len(), 16);
+    assert_eq!(bytes(&color)[4..8], 0.5f32.to_ne_bytes());
+
+    let values = std140::array![std140::float(1.0), std140::float(2.0)];
+    assert_eq!(bytes(&values).len(), 32);
+    assert_eq!(bytes(&values)[16..20], 2.0f32.to_ne_bytes());
+    assert_eq!(bytes(&values)[20..32], [0; 12]);
+
+    let position = std140::vec::vec3(1.0, 2.0, 3.0);
+    assert_eq!(bytes(&position)[12..], [0; 4]);
+
+    let values = std140::unbounded_array![std140::float, std140::float(1.0), std140::float(2.0)];
+    assert_eq!(bytes(&values).len(), 32);
+    assert_eq!(bytes(&values)[4..16], [0; 12]);
+    assert_eq!(bytes(&values)[16..20], 2.0f32.to_ne_bytes());
+}
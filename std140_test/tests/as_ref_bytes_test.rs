@@ -0,0 +1,31 @@
+#[std140::repr_std140(as_ref)]
+struct Uniforms {
+    color: std140::vec::vec4,
+    exposure: std140::float,
+    gamma: std140::float,
+    tone_mapping: std140::uint,
+    flags: std140::uint,
+}
+
+fn byte_len(bytes: impl AsRef<[u8]>) -> usize {
+    bytes.as_ref().len()
+}
+
+#[test]
+fn as_ref_bytes_test() {
+    let uniforms = Uniforms {
+        color: std140::vec::vec4(1.0, 0.5, 0.25, 1.0),
+        exposure: std140::float(2.0),
+        gamma: std140::float(2.2),
+        tone_mapping: std140::uint(1),
+        flags: std140::uint(0),
+    };
+
+    let bytes: &[u8] = uniforms.as_ref();
+
+    assert_eq!(bytes.len(), 32);
+    assert_eq!(bytes[..4], 1.0f32.to_ne_bytes());
+    assert_eq!(bytes[16..20], 2.0f32.to_ne_bytes());
+    assert_eq!(bytes[24..28], 1u32.to_ne_bytes());
+    assert_eq!(byte_len(&uniforms), 32);
+}
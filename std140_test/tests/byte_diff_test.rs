@@ -0,0 +1,66 @@
+#[std140::repr_std140]
+struct Uniforms {
+    color: std140::vec::vec4,
+    intensity: std140::float,
+    light_count: std140::uint,
+    material_count: std140::uint,
+    flags: std140::uint,
+}
+
+fn uniforms() -> Uniforms {
+    Uniforms {
+        color: std140::vec::vec4(1.0, 0.5, 0.25, 1.0),
+        intensity: std140::float(1.0),
+        light_count: std140::uint(4),
+        material_count: std140::uint(2),
+        flags: std140::uint(0),
+    }
+}
+
+#[test]
+fn first_byte_diff_equal_test() {
+    assert_eq!(std140::first_byte_diff(&uniforms(), &uniforms()), None);
+}
+
+#[test]
+fn first_byte_diff_test() {
+    let a = uniforms();
+    let b = Uniforms {
+        light_count: std140::uint(5),
+        ..uniforms()
+    };
+
+    assert_eq!(std140::first_byte_diff(&a, &b), Some(20));
+
+    let c = Uniforms {
+        color: std140::vec::vec4(1.0, 0.5, 0.5, 1.0),
+        ..uniforms()
+    };
+
+    // 0.25 and 0.5 only differ in the upper two bytes of the third component.
+    assert_eq!(std140::first_byte_diff(&a, &c), Some(10));
+}
+
+#[test]
+fn first_byte_diff_ignores_padding_test() {
+    #[std140::repr_std140]
+    struct Light {
+        position: std140::vec::vec3,
+        intensity: std140::float,
+    }
+
+    let a = Light {
+        position: std140::vec::vec3(1.0, 2.0, 3.0),
+        intensity: std140::float(0.5),
+    };
+    let mut b = Light {
+        position: std140::vec::vec3(1.0, 2.0, 3.0),
+        intensity: std140::float(0.5),
+    };
+    b.position.set_padding([0xff; 4]);
+
+    assert_eq!(std140::first_byte_diff(&a, &b), None);
+
+    b.intensity = std140::float(1.0);
+    assert_eq!(std140::first_byte_diff(&a, &b), Some(18));
+}
@@ -0,0 +1,80 @@
+use std140::Std140Struct;
+
+#[std140::repr_std140]
+struct Uniforms {
+    color: std140::vec::vec4,
+    light_count: std140::uint,
+    intensity: std140::float,
+    offset: std140::int,
+    mode: std140::uint,
+}
+
+fn uniforms() -> Uniforms {
+    Uniforms {
+        color: std140::vec::vec4(1.0, 0.0, 0.0, 1.0),
+        light_count: std140::uint(1),
+        intensity: std140::float(0.5),
+        offset: std140::int(-2),
+        mode: std140::uint(0x0102_0304),
+    }
+}
+
+#[test]
+fn to_std140_bytes_be_test() {
+    let bytes = uniforms().to_std140_bytes_be();
+
+    assert_eq!(bytes.len(), 32);
+    assert_eq!(bytes[0..4], 1.0f32.to_be_bytes());
+    assert_eq!(bytes[16..20], [0, 0, 0, 1]);
+    assert_eq!(bytes[20..24], 0.5f32.to_be_bytes());
+    assert_eq!(bytes[24..28], (-2i32).to_be_bytes());
+    assert_eq!(bytes[28..32], [1, 2, 3, 4]);
+}
+
+#[test]
+fn to_std140_bytes_le_test() {
+    let bytes = uniforms().to_std140_bytes_le();
+
+    assert_eq!(bytes.len(), 32);
+    assert_eq!(bytes[0..4], 1.0f32.to_le_bytes());
+    assert_eq!(bytes[16..20], [1, 0, 0, 0]);
+    assert_eq!(bytes[28..32], [4, 3, 2, 1]);
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy)]
+#[repr(C, align(8))]
+struct double(f64);
+
+impl std140::glsl::GlslType for double {
+    const GLSL_NAME: &'static str = "double";
+}
+
+unsafe impl std140::ReprStd140 for double {
+    const STD140_KIND: std140::layout::MemberKind = std140::layout::MemberKind::Scalar;
+    const STD140_PADDING: usize = 0;
+    const STD140_SCALAR_SIZE: usize = 8;
+}
+
+#[std140::repr_std140]
+struct DoubleUniforms {
+    value: double,
+    count: std140::uint,
+}
+
+#[test]
+fn to_std140_bytes_eight_byte_scalar_test() {
+    let uniforms = DoubleUniforms {
+        value: double(1.5),
+        count: std140::uint(1),
+    };
+
+    let be = uniforms.to_std140_bytes_be();
+    let le = uniforms.to_std140_bytes_le();
+
+    assert_eq!(be[0..8], 1.5f64.to_be_bytes());
+    assert_eq!(be[8..12], [0, 0, 0, 1]);
+    assert_eq!(le[0..8], 1.5f64.to_le_bytes());
+    assert_eq!(le[8..12], [1, 0, 0, 0]);
+    assert!(be[12..].iter().chain(&le[12..]).all(|&byte| byte == 0));
+}
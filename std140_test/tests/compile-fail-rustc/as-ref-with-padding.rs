@@ -0,0 +1,7 @@
+#[std140::repr_std140(as_ref)]
+struct Uniforms {
+    position: std140::vec::vec3,
+    intensity: std140::float,
+}
+
+fn main() {}
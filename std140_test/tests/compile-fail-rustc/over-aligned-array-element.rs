@@ -0,0 +1,21 @@
+use std140::{
+    array::AlignmentedElement, glsl::GlslType, layout::MemberKind, ReprStd140, Std140ArrayElement,
+};
+
+#[repr(C, align(32))]
+#[derive(Clone, Copy)]
+struct Wide([f32; 8]);
+
+impl GlslType for Wide {
+    const GLSL_NAME: &'static str = "Wide";
+}
+
+unsafe impl ReprStd140 for Wide {
+    const STD140_KIND: MemberKind = MemberKind::Scalar;
+    const STD140_PADDING: usize = 0;
+}
+unsafe impl Std140ArrayElement for Wide {}
+
+fn main() {
+    let _elements = std140::array::array::new([AlignmentedElement::new(Wide([0.0; 8]))]);
+}
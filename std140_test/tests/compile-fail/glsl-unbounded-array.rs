@@ -0,0 +1,7 @@
+#[std140::repr_std140(glsl)]
+struct Particles {
+    count: std140::uint,
+    positions: std140::unbounded_array::unbounded_array<std140::vec::vec4>,
+}
+
+fn main() {}
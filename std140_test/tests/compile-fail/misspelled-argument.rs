@@ -0,0 +1,7 @@
+#[std140::repr_std140(glsl, bulider)]
+struct PointLight {
+    position: std140::vec::vec3,
+    intensity: std140::float,
+}
+
+fn main() {}
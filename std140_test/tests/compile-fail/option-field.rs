@@ -0,0 +1,8 @@
+#[std140::repr_std140]
+struct Material {
+    roughness: Option<std140::float>,
+}
+
+fn main() {
+
+}
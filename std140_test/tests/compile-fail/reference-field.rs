@@ -0,0 +1,8 @@
+#[std140::repr_std140]
+struct Camera<'a> {
+    position: &'a std140::vec::vec3,
+}
+
+fn main() {
+
+}
@@ -0,0 +1,6 @@
+#[std140::repr_std140]
+struct Empty;
+
+fn main() {
+
+}
@@ -0,0 +1,9 @@
+#[std140::repr_std140(derive(Cop))]
+struct PointLight {
+    position: std140::vec::vec3,
+    intensity: std140::float,
+}
+
+fn main() {
+
+}
@@ -0,0 +1,8 @@
+#[std140::repr_std140]
+struct Lights {
+    intensities: Vec<std140::float>,
+}
+
+fn main() {
+
+}
@@ -0,0 +1,14 @@
+#[std140::repr_std140(as_ref)]
+struct Uniforms {
+    transform: std140::mat::mat4x4,
+    color: std140::vec::vec4,
+}
+
+fn main() {
+    let uniforms = Uniforms {
+        transform: std140::mat::mat4x4::identity(),
+        color: std140::vec::vec4(1.0, 1.0, 1.0, 1.0),
+    };
+
+    assert_eq!(AsRef::<[u8]>::as_ref(&uniforms).len(), 80);
+}
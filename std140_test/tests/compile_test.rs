@@ -1,18 +1,21 @@
-use ::{compiletest_rs as compiletest, std::path::PathBuf};
-
-fn run_mode(mode: &'static str) {
-    let mut config = compiletest::Config::default();
-
-    config.mode = mode.parse().expect("Invalid mode");
-    config.src_base = PathBuf::from(format!("tests/{}", mode));
-    config.link_deps(); // Populate config.target_rustcflags with dependencies on the path
-    config.clean_rmeta(); // If your tests import the parent crate, this helps with E0464
-
-    compiletest::run_tests(&config);
+#[test]
+fn compile_test() {
+    // Only errors reported by #[repr_std140] itself, whose output is the same on every compiler.
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/compile-fail/*.rs");
 }
 
+/// Cases whose expected output is rustc's own diagnostics, such as unsatisfied trait bounds and
+/// constant evaluation errors, which change between compiler versions. Run them with
+/// `cargo test -- --ignored` and refresh the `.stderr` files with `TRYBUILD=overwrite` after
+/// checking the new output.
 #[test]
-#[ignore]
-fn compile_test() {
-    run_mode("compile-fail");
+#[ignore = "expected output depends on the rustc version"]
+fn rustc_compile_test() {
+    let cases = trybuild::TestCases::new();
+
+    // Pass cases make trybuild build rather than only check the compile-fail cases, so errors
+    // raised while evaluating constants during code generation are caught as well.
+    cases.pass("tests/compile-pass/*.rs");
+    cases.compile_fail("tests/compile-fail-rustc/*.rs");
 }
@@ -0,0 +1,38 @@
+#[test]
+fn fill_bytes_float_test() {
+    let bytes = std140::fill_bytes(4, std140::float(0.5));
+
+    assert_eq!(bytes.len(), 4 * 16);
+    for element in bytes.chunks(16) {
+        assert_eq!(element[..4], 0.5f32.to_ne_bytes());
+        assert_eq!(element[4..], [0; 12]);
+    }
+}
+
+#[test]
+fn fill_bytes_matrix_test() {
+    let value = std140::mat2x2(std140::vec::vec2(1.0, 2.0), std140::vec::vec2(3.0, 4.0));
+    let bytes = std140::fill_bytes(2, value);
+
+    assert_eq!(bytes.len(), 2 * 32);
+    assert_eq!(bytes[32 + 20..32 + 24], 4.0f32.to_ne_bytes());
+    assert_eq!(bytes[32 + 24..], [0; 8]);
+}
+
+#[test]
+fn fill_bytes_empty_test() {
+    assert!(std140::fill_bytes(0, std140::uint(1)).is_empty());
+}
+
+#[test]
+fn fill_bytes_zeroes_element_padding_test() {
+    let mut value = std140::vec::vec3(1.0, 2.0, 3.0);
+    value.set_padding([0xff; 4]);
+    let bytes = std140::fill_bytes(2, value);
+
+    assert_eq!(bytes.len(), 2 * 16);
+    for element in bytes.chunks(16) {
+        assert_eq!(element[8..12], 3.0f32.to_ne_bytes());
+        assert_eq!(element[12..], [0; 4]);
+    }
+}
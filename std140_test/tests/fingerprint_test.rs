@@ -0,0 +1,80 @@
+use std140::Std140Struct;
+
+#[std140::repr_std140]
+struct Uniforms {
+    color: std140::vec::vec4,
+    intensity: std140::float,
+    light_count: std140::uint,
+    material_count: std140::uint,
+    flags: std140::uint,
+}
+
+fn uniforms() -> Uniforms {
+    Uniforms {
+        color: std140::vec::vec4(1.0, 0.5, 0.25, 1.0),
+        intensity: std140::float(1.0),
+        light_count: std140::uint(4),
+        material_count: std140::uint(2),
+        flags: std140::uint(0),
+    }
+}
+
+#[test]
+fn std140_fingerprint_test() {
+    assert_eq!(
+        uniforms().std140_fingerprint(),
+        uniforms().std140_fingerprint()
+    );
+
+    let changed = Uniforms {
+        light_count: std140::uint(5),
+        ..uniforms()
+    };
+
+    assert_ne!(
+        uniforms().std140_fingerprint(),
+        changed.std140_fingerprint()
+    );
+}
+
+#[test]
+fn std140_fingerprint_fnv1a_test() {
+    #[std140::repr_std140]
+    struct Single {
+        value: std140::vec::vec4,
+    }
+
+    // The FNV-1a hash of 16 zero bytes.
+    assert_eq!(
+        Single {
+            value: std140::vec::vec4(0.0, 0.0, 0.0, 0.0)
+        }
+        .std140_fingerprint(),
+        0x8820_1fb9_60ff_6465
+    );
+}
+
+#[test]
+fn std140_fingerprint_ignores_padding_test() {
+    #[std140::repr_std140]
+    struct Light {
+        position: std140::vec::vec3,
+        intensity: std140::float,
+    }
+
+    let a = Light {
+        position: std140::vec::vec3(1.0, 2.0, 3.0),
+        intensity: std140::float(0.5),
+    };
+    let mut b = Light {
+        position: std140::vec::vec3(1.0, 2.0, 3.0),
+        intensity: std140::float(0.5),
+    };
+    b.position.set_padding([0xff; 4]);
+
+    assert_eq!(a.std140_fingerprint(), b.std140_fingerprint());
+
+    b.intensity = std140::float(1.0);
+
+    assert_ne!(a.std140_fingerprint(), b.std140_fingerprint());
+}
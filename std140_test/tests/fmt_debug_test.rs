@@ -128,3 +128,55 @@ fn fmt_mat4x4_test() {
         std140::vec::vec4(0.0, 0.0, 0.0, 1.0),
     )), "mat4x4[vec4(1.0, 0.0, 0.0, 0.0), vec4(0.0, 1.0, 0.0, 0.0), vec4(0.0, 0.0, 1.0, 0.0), vec4(0.0, 0.0, 0.0, 1.0)]");
 }
+
+#[test]
+fn fmt_mat3x3_alternate_test() {
+    let matrix = std140::mat::mat3x3::identity();
+
+    assert_ne!(format!("{:?}", matrix), format!("{:#?}", matrix));
+    assert_eq!(
+        format!(
+            "{:#?}",
+            std140::mat2x2(std140::vec::vec2(1.0, 0.0), std140::vec::vec2(0.0, 1.0))
+        ),
+        "mat2x2[
+    vec2(
+        1.0,
+        0.0,
+    ),
+    vec2(
+        0.0,
+        1.0,
+    ),
+]"
+    );
+}
+
+#[test]
+fn fmt_mat_nested_alternate_test() {
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Transform {
+        model: std140::mat::mat2x2,
+    }
+
+    let transform = Transform {
+        model: std140::mat::mat2x2::identity(),
+    };
+
+    assert_eq!(
+        format!("{:#?}", transform),
+        "Transform {
+    model: mat2x2[
+        vec2(
+            1.0,
+            0.0,
+        ),
+        vec2(
+            0.0,
+            1.0,
+        ),
+    ],
+}"
+    );
+}
@@ -0,0 +1,68 @@
+use std140::{
+    array::array,
+    boolean, float,
+    glsl::GlslType,
+    int,
+    mat::{mat2x3, mat3x3, mat4x4},
+    packed_rgba8, uint,
+    unbounded_array::unbounded_array,
+    vec::{bvec2, ivec4, uvec3, vec3},
+};
+
+#[std140::repr_std140]
+struct PointLight {
+    position: vec3,
+    intensity: float,
+}
+
+fn glsl_type_name<T: GlslType>() -> &'static str {
+    T::GLSL_NAME
+}
+
+#[test]
+fn glsl_type_name_scalar_vector_test() {
+    assert_eq!(glsl_type_name::<float>(), "float");
+    assert_eq!(glsl_type_name::<int>(), "int");
+    assert_eq!(glsl_type_name::<uint>(), "uint");
+    assert_eq!(glsl_type_name::<boolean>(), "bool");
+    assert_eq!(glsl_type_name::<packed_rgba8>(), "uint");
+    assert_eq!(glsl_type_name::<vec3>(), "vec3");
+    assert_eq!(glsl_type_name::<ivec4>(), "ivec4");
+    assert_eq!(glsl_type_name::<uvec3>(), "uvec3");
+    assert_eq!(glsl_type_name::<bvec2>(), "bvec2");
+}
+
+#[test]
+fn glsl_type_name_matrix_test() {
+    assert_eq!(<mat4x4 as GlslType>::GLSL_NAME, "mat4");
+    assert_eq!(glsl_type_name::<mat3x3>(), "mat3");
+    assert_eq!(glsl_type_name::<mat2x3>(), "mat2x3");
+}
+
+#[test]
+fn glsl_type_name_array_test() {
+    assert_eq!(glsl_type_name::<array<vec3, 4>>(), "vec3[4]");
+    assert_eq!(glsl_type_name::<array<array<uint, 3>, 2>>(), "uint[2][3]");
+    assert_eq!(glsl_type_name::<array<mat4x4, 128>>(), "mat4[128]");
+    assert_eq!(glsl_type_name::<unbounded_array<vec3>>(), "vec3[]");
+    assert_eq!(
+        glsl_type_name::<unbounded_array<array<int, 10>>>(),
+        "int[][10]"
+    );
+}
+
+#[test]
+fn glsl_type_name_struct_test() {
+    assert_eq!(glsl_type_name::<PointLight>(), "PointLight");
+    assert_eq!(glsl_type_name::<array<PointLight, 2>>(), "PointLight[2]");
+}
+
+#[test]
+fn glsl_type_trait_test() {
+    assert_eq!(<mat4x4 as GlslType>::GLSL_NAME, "mat4");
+    assert_eq!(
+        <array<PointLight, 3> as GlslType>::GLSL_NAME,
+        "PointLight[3]"
+    );
+    assert_eq!(<PointLight as GlslType>::GLSL_NAME, "PointLight");
+}
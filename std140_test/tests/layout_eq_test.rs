@@ -0,0 +1,109 @@
+use std140::{float, uint, vec::vec4, Std140Struct};
+
+#[std140::repr_std140]
+struct UniformsV1 {
+    color: vec4,
+    light_count: uint,
+    intensity: float,
+}
+
+#[std140::repr_std140]
+struct UniformsV2 {
+    tint: vec4,
+    count: uint,
+    brightness: float,
+}
+
+#[std140::repr_std140]
+struct UniformsV3 {
+    tint: vec4,
+    count: uint,
+}
+
+#[std140::repr_std140]
+struct UniformsV4 {
+    count: uint,
+    tint: vec4,
+}
+
+#[test]
+fn layout_eq_test() {
+    let v1 = UniformsV1 {
+        color: vec4(1.0, 0.5, 0.0, 1.0),
+        light_count: uint(2),
+        intensity: float(0.75),
+    };
+    let v2 = UniformsV2 {
+        tint: vec4(1.0, 0.5, 0.0, 1.0),
+        count: uint(2),
+        brightness: float(0.75),
+    };
+
+    assert!(v1.layout_eq(&v2));
+    assert!(v2.layout_eq(&v1));
+
+    let v2 = UniformsV2 {
+        brightness: float(1.0),
+        ..v2
+    };
+
+    assert!(!v1.layout_eq(&v2));
+}
+
+#[test]
+fn layout_eq_different_layout_test() {
+    let v1 = UniformsV1 {
+        color: vec4(1.0, 0.5, 0.0, 1.0),
+        light_count: uint(2),
+        intensity: float(0.0),
+    };
+    let v3 = UniformsV3 {
+        tint: vec4(1.0, 0.5, 0.0, 1.0),
+        count: uint(2),
+    };
+    let v4 = UniformsV4 {
+        count: uint(2),
+        tint: vec4(1.0, 0.5, 0.0, 1.0),
+    };
+
+    // Same size, but a different number of members.
+    assert_eq!(
+        UniformsV1::STD140_LAYOUT.size,
+        UniformsV3::STD140_LAYOUT.size
+    );
+    assert!(!v1.layout_eq(&v3));
+
+    // Same members, but at different offsets.
+    assert!(!v3.layout_eq(&v4));
+}
+
+#[std140::repr_std140]
+struct LightV1 {
+    position: std140::vec::vec3,
+    intensities: std140::array::array<float, 2>,
+}
+
+#[std140::repr_std140]
+struct LightV2 {
+    origin: std140::vec::vec3,
+    brightness: std140::array::array<float, 2>,
+}
+
+#[test]
+fn layout_eq_ignores_member_padding_test() {
+    let v1 = LightV1 {
+        position: std140::vec::vec3(1.0, 2.0, 3.0),
+        intensities: std140::array![float(0.5), float(1.0)],
+    };
+    let mut v2 = LightV2 {
+        origin: std140::vec::vec3(1.0, 2.0, 3.0),
+        brightness: std140::array![float(0.5), float(1.0)],
+    };
+    v2.origin.set_padding([0xff; 4]);
+
+    assert!(v1.layout_eq(&v2));
+
+    v2.origin.2 = 4.0;
+
+    assert!(!v1.layout_eq(&v2));
+}
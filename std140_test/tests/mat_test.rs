@@ -0,0 +1,532 @@
+use std140::mat::{mat2x3, mat4x3, mat4x4, Matrix};
+
+#[test]
+fn matrix_dimensions_test() {
+    assert_eq!(mat4x3::COLUMNS, 4);
+    assert_eq!(mat4x3::ROWS, 3);
+    assert_eq!(mat2x3::COLUMNS, 2);
+    assert_eq!(mat2x3::ROWS, 3);
+    assert_eq!(mat4x4::COLUMNS, 4);
+    assert_eq!(mat4x4::ROWS, 4);
+}
+
+#[test]
+fn matrix_column_type_test() {
+    let column: <mat4x3 as Matrix>::Column = std140::vec::vec3(1.0, 2.0, 3.0);
+
+    assert_eq!(column, std140::vec::vec3(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn matrix_neg_test() {
+    assert_eq!(
+        -std140::mat2x3(
+            std140::vec::vec3(1.0, -2.0, 3.0),
+            std140::vec::vec3(-4.0, 5.0, -6.0),
+        ),
+        std140::mat2x3(
+            std140::vec::vec3(-1.0, 2.0, -3.0),
+            std140::vec::vec3(4.0, -5.0, 6.0),
+        )
+    );
+}
+
+#[test]
+fn matrix_add_sub_test() {
+    assert_eq!(mat4x4::identity() - mat4x4::identity(), mat4x4::zero());
+    assert_eq!(
+        -mat4x4::identity() + mat4x4::identity() + mat4x4::identity(),
+        mat4x4::identity()
+    );
+
+    let negated = -std140::mat::mat3x3::identity();
+    for (index, column) in negated.into_iter().enumerate() {
+        assert_eq!(column[index], -1.0);
+    }
+
+    let mut m = mat2x3::zero();
+    m += std140::mat2x3(
+        std140::vec::vec3(1.0, 2.0, 3.0),
+        std140::vec::vec3(4.0, 5.0, 6.0),
+    );
+    m -= std140::mat2x3(
+        std140::vec::vec3(1.0, 1.0, 1.0),
+        std140::vec::vec3(1.0, 1.0, 1.0),
+    );
+    assert_eq!(
+        m,
+        std140::mat2x3(
+            std140::vec::vec3(0.0, 1.0, 2.0),
+            std140::vec::vec3(3.0, 4.0, 5.0),
+        )
+    );
+}
+
+#[test]
+fn matrix_consts_test() {
+    const IDENTITY: mat4x4 = mat4x4::IDENTITY;
+    const ZERO: mat2x3 = mat2x3::ZERO;
+
+    assert_eq!(IDENTITY, mat4x4::identity());
+    assert_eq!(ZERO, mat2x3::zero());
+    assert_eq!(std140::mat::mat3x3::IDENTITY[2].2, 1.0);
+}
+
+#[test]
+fn matrix_into_iter_test() {
+    let matrix = std140::mat3x2(
+        std140::vec::vec2(1.0, 2.0),
+        std140::vec::vec2(3.0, 4.0),
+        std140::vec::vec2(5.0, 6.0),
+    );
+
+    let mut sum = std140::vec::vec2::zero();
+
+    for column in &matrix {
+        sum = std140::vec::vec2(sum.0 + column.0, sum.1 + column.1);
+    }
+
+    assert_eq!(sum, std140::vec::vec2(9.0, 12.0));
+
+    let columns: Vec<std140::vec::vec2> = matrix.into_iter().rev().collect();
+
+    assert_eq!(
+        columns,
+        vec![
+            std140::vec::vec2(5.0, 6.0),
+            std140::vec::vec2(3.0, 4.0),
+            std140::vec::vec2(1.0, 2.0),
+        ]
+    );
+}
+
+#[test]
+fn matrix_index_test() {
+    let mut matrix = mat4x4::identity();
+
+    assert_eq!(matrix[1], std140::vec::vec4(0.0, 1.0, 0.0, 0.0));
+
+    matrix[3] = std140::vec::vec4(1.0, 2.0, 3.0, 1.0);
+    matrix[0].0 = 2.0;
+
+    assert_eq!(matrix[3], std140::vec::vec4(1.0, 2.0, 3.0, 1.0));
+    assert_eq!(matrix[0], std140::vec::vec4(2.0, 0.0, 0.0, 0.0));
+    assert_eq!(matrix.len(), 4);
+}
+
+#[test]
+#[should_panic]
+fn matrix_index_out_of_bounds_test() {
+    let matrix = std140::mat::mat2x2::identity();
+
+    let _ = matrix[2];
+}
+
+#[test]
+fn matrix_lerp_test() {
+    let a = mat4x4::zero();
+    let b = mat4x4::identity();
+    let half = a.lerp(b, 0.5);
+
+    assert_eq!(a.lerp(b, 0.0), a);
+    assert_eq!(a.lerp(b, 1.0), b);
+    assert_eq!(half[0], std140::vec::vec4(0.5, 0.0, 0.0, 0.0));
+    assert_eq!(half[3], std140::vec::vec4(0.0, 0.0, 0.0, 0.5));
+}
+
+#[test]
+fn matrix_iter_columns_mut_test() {
+    let mut m = std140::mat::mat3x3::identity();
+
+    for (index, column) in m.iter_columns_mut().enumerate() {
+        *column *= (index + 1) as f32;
+    }
+
+    for column in &mut m {
+        column.2 += 1.0;
+    }
+
+    assert_eq!(
+        m,
+        std140::mat3x3(
+            std140::vec::vec3(1.0, 0.0, 1.0),
+            std140::vec::vec3(0.0, 2.0, 1.0),
+            std140::vec::vec3(0.0, 0.0, 4.0),
+        )
+    );
+}
+
+#[test]
+fn matrix_float_checks_test() {
+    let mut m = mat4x4::identity();
+
+    assert!(m.is_finite());
+    assert!(!m.is_nan());
+
+    m[2].1 = f32::NAN;
+
+    assert!(m.is_nan());
+    assert!(!m.is_finite());
+
+    let mut m = mat2x3::zero();
+    m[1].2 = f32::INFINITY;
+
+    assert!(!m.is_finite());
+    assert!(!m.is_nan());
+}
+
+#[test]
+fn matrix_abs_test() {
+    let m = std140::mat2x3(
+        std140::vec::vec3(-1.0, 2.0, -0.5),
+        std140::vec::vec3(0.0, -4.0, f32::NEG_INFINITY),
+    );
+
+    assert_eq!(
+        m.abs(),
+        std140::mat2x3(
+            std140::vec::vec3(1.0, 2.0, 0.5),
+            std140::vec::vec3(0.0, 4.0, f32::INFINITY),
+        )
+    );
+    assert_eq!((-mat4x4::identity()).abs(), mat4x4::identity());
+
+    let mut with_nan = mat4x4::identity();
+    with_nan[0].0 = f32::NAN;
+    assert!(!with_nan.is_finite());
+    assert!(!with_nan.abs().is_finite());
+}
+
+#[test]
+fn matrix_iter_flat_test() {
+    assert_eq!(
+        std140::mat::mat2x2::identity()
+            .iter_flat()
+            .collect::<Vec<_>>(),
+        vec![1.0, 0.0, 0.0, 1.0]
+    );
+
+    let m = std140::mat2x3(
+        std140::vec::vec3(1.0, 2.0, 3.0),
+        std140::vec::vec3(4.0, 5.0, 6.0),
+    );
+    assert_eq!(
+        m.iter_flat().collect::<Vec<_>>(),
+        vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]
+    );
+    assert_eq!(mat4x4::identity().iter_flat().count(), 16);
+}
+
+#[test]
+fn matrix_to_flat_array_test() {
+    let m = std140::mat2x3(
+        std140::vec::vec3(1.0, 2.0, 3.0),
+        std140::vec::vec3(4.0, 5.0, 6.0),
+    );
+
+    assert_eq!(m.to_flat_array(), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert_eq!(
+        std140::mat::mat2x2::identity().to_flat_array(),
+        [1.0, 0.0, 0.0, 1.0]
+    );
+    assert_eq!(mat4x4::identity().to_flat_array().len(), 16);
+}
+
+#[test]
+fn matrix_as_slice_test() {
+    let mut m = mat4x4::identity();
+
+    assert_eq!(m.as_slice().len(), 16);
+    assert_eq!(m.as_slice(), &m.to_flat_array()[..]);
+
+    m.as_mut_slice()[12] = 5.0;
+    assert_eq!(m[3].0, 5.0);
+
+    let m = std140::mat2x4(
+        std140::vec::vec4(1.0, 2.0, 3.0, 4.0),
+        std140::vec::vec4(5.0, 6.0, 7.0, 8.0),
+    );
+    assert_eq!(m.as_slice(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+}
+
+#[test]
+fn matrix_array_stride_test() {
+    use std::mem::{align_of, size_of};
+    use std140::{array::array, mat::mat3x2};
+
+    let matrices = std140::array![mat4x4::identity(); 4];
+    assert_eq!(size_of::<array<mat4x4, 4>>(), 4 * 64);
+    assert_eq!(align_of::<array<mat4x4, 4>>(), 16);
+    assert_eq!(matrices[3].0, mat4x4::IDENTITY);
+
+    let base = &matrices as *const _ as usize;
+    assert_eq!(&matrices[2].0 as *const _ as usize - base, 2 * 64);
+
+    assert_eq!(size_of::<mat2x3>(), 32);
+    assert_eq!(size_of::<array<mat2x3, 3>>(), 3 * 32);
+    assert_eq!(size_of::<array<mat3x2, 2>>(), 2 * 48);
+}
+
+#[test]
+fn matrix_mul_assign_test() {
+    use std140::vec::{vec2, vec3, vec4};
+
+    let mut m = std140::mat4x4(
+        vec4(1.0, 2.0, 3.0, 4.0),
+        vec4(5.0, 6.0, 7.0, 8.0),
+        vec4(9.0, 10.0, 11.0, 12.0),
+        vec4(13.0, 14.0, 15.0, 16.0),
+    );
+    let original = m;
+    m *= mat4x4::identity();
+    assert_eq!(m, original);
+
+    // Rotating by 90 degrees twice flips both axes.
+    let mut rotation = std140::mat2x2(vec2(0.0, 1.0), vec2(-1.0, 0.0));
+    assert_eq!(rotation * vec2(1.0, 0.0), vec2(0.0, 1.0));
+    rotation *= rotation;
+    assert_eq!(rotation, std140::mat2x2(vec2(-1.0, 0.0), vec2(0.0, -1.0)));
+
+    // Translation applied after scaling.
+    let scale = std140::mat3x3(
+        vec3(2.0, 0.0, 0.0),
+        vec3(0.0, 2.0, 0.0),
+        vec3(0.0, 0.0, 1.0),
+    );
+    let translation = std140::mat3x3(
+        vec3(1.0, 0.0, 0.0),
+        vec3(0.0, 1.0, 0.0),
+        vec3(5.0, 0.0, 1.0),
+    );
+    assert_eq!(
+        (translation * scale) * vec3(1.0, 1.0, 1.0),
+        vec3(7.0, 2.0, 1.0)
+    );
+}
+
+#[test]
+fn matrix_scale_test() {
+    use std140::vec::vec3;
+
+    let original = std140::mat2x3(vec3(1.0, 2.0, 3.0), vec3(4.0, 5.0, 6.0));
+    let mut m = original;
+    m *= 3.0;
+
+    assert_eq!(m, original * 3.0);
+    assert_eq!(m[1], vec3(12.0, 15.0, 18.0));
+}
+
+#[test]
+fn matrix_euler_round_trip_test() {
+    use std140::mat::mat3x3;
+
+    for &(x, y, z) in &[
+        (0.3, -0.5, 1.2),
+        (-2.5, 1.0, 3.0),
+        (0.0, 0.0, 0.0),
+        (1.5, -1.2, -0.7),
+    ] {
+        let (rx, ry, rz) = mat3x3::from_euler_xyz(x, y, z).to_euler_xyz();
+
+        assert!((rx - x).abs() < 1e-5, "x: {} != {}", rx, x);
+        assert!((ry - y).abs() < 1e-5, "y: {} != {}", ry, y);
+        assert!((rz - z).abs() < 1e-5, "z: {} != {}", rz, z);
+    }
+}
+
+#[test]
+fn matrix_euler_gimbal_lock_test() {
+    use std::f32::consts::FRAC_PI_2;
+    use std140::mat::mat3x3;
+
+    for &y in &[FRAC_PI_2, -FRAC_PI_2] {
+        let rotation = mat3x3::from_euler_xyz(0.4, y, 0.3);
+        let (x, ry, z) = rotation.to_euler_xyz();
+
+        assert_eq!(z, 0.0);
+        assert!((ry - y).abs() < 1e-3);
+
+        let recreated = mat3x3::from_euler_xyz(x, ry, z);
+        assert!((recreated - rotation).abs().iter_flat().all(|e| e < 1e-5));
+    }
+}
+
+#[test]
+fn matrix_as_ref_test() {
+    let m = mat4x4::identity();
+    let floats: &[f32] = m.as_ref();
+
+    assert_eq!(floats.len(), 16);
+    assert_eq!(floats, m.to_flat_array());
+}
+
+#[test]
+fn matrix_trace_is_identity_test() {
+    use std140::mat::{mat2x2, mat3x3};
+
+    assert_eq!(mat3x3::identity().trace(), 3.0);
+    assert!(mat3x3::identity().is_identity(1e-6));
+    assert!(!(mat3x3::identity() * 2.0).is_identity(1e-6));
+    assert_eq!((mat4x4::identity() * 2.0).trace(), 8.0);
+
+    let nearly = mat2x2::identity()
+        + std140::mat2x2(std140::vec::vec2(1e-7, 0.0), std140::vec::vec2(0.0, -1e-7));
+    assert!(nearly.is_identity(1e-6));
+    assert!(!nearly.is_identity(0.0));
+}
+
+#[test]
+fn matrix_columns_round_trip_test() {
+    use std140::{mat::mat3x3, vec::vec3};
+
+    let columns = [
+        vec3(1.0, 2.0, 3.0),
+        vec3(4.0, 5.0, 6.0),
+        vec3(7.0, 8.0, 9.0),
+    ];
+    let m = mat3x3::from_columns(columns);
+
+    assert_eq!(m, std140::mat3x3(columns[0], columns[1], columns[2]));
+    assert_eq!(m.into_columns(), columns);
+
+    let doubled = mat3x3::from_columns(m.into_columns().map(|column| column * 2.0));
+    assert_eq!(doubled, m * 2.0);
+}
+
+#[test]
+fn matrix_column_mutators_test() {
+    use std140::vec::vec4;
+
+    let columns = [
+        vec4(1.0, 2.0, 3.0, 4.0),
+        vec4(5.0, 6.0, 7.0, 8.0),
+        vec4(9.0, 10.0, 11.0, 12.0),
+        vec4(13.0, 14.0, 15.0, 16.0),
+    ];
+    let mut m = mat4x4::from_columns(columns);
+
+    m.swap_columns(0, 3);
+    assert_eq!(
+        m.into_columns(),
+        [columns[3], columns[1], columns[2], columns[0]]
+    );
+
+    m.negate_column(1);
+    assert_eq!(m[1], -columns[1]);
+    assert_eq!(m[2], columns[2]);
+}
+
+#[test]
+#[should_panic]
+fn matrix_negate_column_out_of_bounds_test() {
+    mat2x3::zero().negate_column(2);
+}
+
+#[test]
+fn matrix_row_major_macro_test() {
+    use std140::{mat::mat4x2, vec::vec3};
+
+    #[rustfmt::skip]
+    let m = std140::mat4x4![
+        1.0, 2.0, 3.0, 4.0,
+        5.0, 6.0, 7.0, 8.0,
+        9.0, 10.0, 11.0, 12.0,
+        13.0, 14.0, 15.0, 16.0,
+    ];
+    assert_eq!(
+        m,
+        mat4x4::from_rows([
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 10.0, 11.0, 12.0],
+            [13.0, 14.0, 15.0, 16.0],
+        ])
+    );
+
+    #[rustfmt::skip]
+    let m = std140::mat2x3![
+        1.0, 2.0,
+        3.0, 4.0,
+        5.0, 6.0,
+    ];
+    assert_eq!(
+        m,
+        mat2x3::from_columns([vec3(1.0, 3.0, 5.0), vec3(2.0, 4.0, 6.0)])
+    );
+
+    #[rustfmt::skip]
+    let m = std140::mat4x2![
+        1.0, 2.0, 3.0, 4.0,
+        5.0, 6.0, 7.0, 8.0,
+    ];
+    assert_eq!(
+        m,
+        mat4x2::from_rows([[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]])
+    );
+}
+
+#[test]
+fn mat4x4_trs_test() {
+    use std140::{
+        mat::{mat3x3, mat4x4},
+        vec::{vec3, vec4},
+    };
+
+    let translation = vec3(1.0, -2.0, 3.0);
+
+    assert_eq!(
+        mat4x4::from_translation(translation) * vec4(0.0, 0.0, 0.0, 1.0),
+        vec4(1.0, -2.0, 3.0, 1.0)
+    );
+
+    let rotation = mat3x3::from_euler_xyz(0.3, -0.7, 1.1);
+    let trs = mat4x4::from_scale_rotation_translation(vec3(2.0, 3.0, 4.0), rotation, translation);
+
+    assert_eq!(trs * vec4(0.0, 0.0, 0.0, 1.0), translation.extend(1.0));
+
+    let expected = mat4x4::from_translation(translation)
+        * std140::mat4x4(
+            rotation[0].extend(0.0),
+            rotation[1].extend(0.0),
+            rotation[2].extend(0.0),
+            vec4(0.0, 0.0, 0.0, 1.0),
+        )
+        * std140::mat4x4(
+            vec4(2.0, 0.0, 0.0, 0.0),
+            vec4(0.0, 3.0, 0.0, 0.0),
+            vec4(0.0, 0.0, 4.0, 0.0),
+            vec4(0.0, 0.0, 0.0, 1.0),
+        );
+
+    for (column, expected) in trs.into_columns().iter().zip(expected.into_columns()) {
+        assert!((*column - expected).abs().max_element() < 1e-6);
+    }
+}
+
+#[test]
+fn const_eq_test() {
+    use std140::{
+        boolean, float,
+        mat::mat3x3,
+        vec::{bvec2, uvec2, vec3},
+    };
+
+    const ROTATION: mat3x3 = std140::mat3x3(
+        vec3(1.0, 0.0, 0.0),
+        vec3(0.0, 1.0, 0.0),
+        vec3(0.0, 0.0, 1.0),
+    );
+
+    const _: () = assert!(ROTATION.const_eq(&mat3x3::IDENTITY));
+    const _: () = assert!(!mat4x4::IDENTITY.const_eq(&mat4x4::ZERO));
+    const _: () = assert!(vec3(1.0, 2.0, 3.0).const_eq(&vec3(1.0, 2.0, 3.0)));
+    const _: () = assert!(!uvec2(1, 2).const_eq(&uvec2(1, 3)));
+    const _: () = assert!(
+        !bvec2(boolean::True, boolean::False).const_eq(&bvec2(boolean::True, boolean::True))
+    );
+
+    // Floats are compared bitwise.
+    assert!(float(f32::NAN).const_eq(&float(f32::NAN)));
+    assert!(!float(0.0).const_eq(&float(-0.0)));
+    assert!(!mat3x3::IDENTITY.const_eq(&(mat3x3::IDENTITY * f32::NAN)));
+}
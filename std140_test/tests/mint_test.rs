@@ -0,0 +1,69 @@
+use std140::{
+    mat::{mat3x3, mat4x4},
+    vec::{vec3, vec4},
+};
+
+fn quarter_turn_z() -> mint::Quaternion<f32> {
+    let half_sqrt = std::f32::consts::FRAC_1_SQRT_2;
+
+    mint::Quaternion {
+        v: mint::Vector3 {
+            x: 0.0,
+            y: 0.0,
+            z: half_sqrt,
+        },
+        s: half_sqrt,
+    }
+}
+
+fn assert_approx_eq(actual: vec4, expected: vec4) {
+    let difference = actual - expected;
+
+    assert!(
+        difference.map(f32::abs).max_element() < 1e-6,
+        "{:?} != {:?}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn mat3x3_from_mint_quaternion_test() {
+    let rotation = mat3x3::from_mint_quaternion(quarter_turn_z());
+    let expected = [
+        vec3(0.0, 1.0, 0.0),
+        vec3(-1.0, 0.0, 0.0),
+        vec3(0.0, 0.0, 1.0),
+    ];
+
+    for (column, expected) in rotation.into_iter().zip(expected) {
+        assert_approx_eq(
+            vec4(column.0, column.1, column.2, 0.0),
+            vec4(expected.0, expected.1, expected.2, 0.0),
+        );
+    }
+}
+
+#[test]
+fn mat4x4_from_mint_quaternion_test() {
+    let rotation = mat4x4::from_mint_quaternion(quarter_turn_z());
+
+    assert_approx_eq(rotation[0], vec4(0.0, 1.0, 0.0, 0.0));
+    assert_approx_eq(rotation[1], vec4(-1.0, 0.0, 0.0, 0.0));
+    assert_approx_eq(rotation[2], vec4(0.0, 0.0, 1.0, 0.0));
+    assert_approx_eq(rotation[3], vec4(0.0, 0.0, 0.0, 1.0));
+}
+
+#[test]
+fn mint_identity_quaternion_test() {
+    let identity = mint::Quaternion {
+        v: mint::Vector3 {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        },
+        s: 1.0,
+    };
+
+    assert_eq!(mat4x4::from_mint_quaternion(identity), mat4x4::identity());
+}
@@ -0,0 +1,30 @@
+use std140::{mat::mat4x4, vec::vec3, Std140OffsetBuilder};
+
+#[test]
+fn align_up_test() {
+    assert_eq!(std140::align_up(0, 16), 0);
+    assert_eq!(std140::align_up(1, 16), 16);
+    assert_eq!(std140::align_up(16, 16), 16);
+    assert_eq!(std140::align_up(64, 256), 256);
+}
+
+#[test]
+fn offset_builder_test() {
+    let mut builder = Std140OffsetBuilder::new();
+
+    assert_eq!(builder.push::<mat4x4>(), 0);
+    assert_eq!(builder.push::<std140::float>(), 64);
+    assert_eq!(builder.push::<vec3>(), 80);
+    assert_eq!(builder.size(), 96);
+}
+
+#[test]
+fn offset_builder_min_alignment_test() {
+    let mut builder = Std140OffsetBuilder::with_min_alignment(256);
+
+    builder.push::<mat4x4>();
+    builder.push::<vec3>();
+
+    assert_eq!(builder.offsets(), &[0, 256]);
+    assert_eq!(builder.size(), 272);
+}
@@ -0,0 +1,27 @@
+use std140::{packed_rgba8, vec::vec4};
+
+#[test]
+fn packed_rgba8_layout_test() {
+    assert_eq!(std::mem::size_of::<packed_rgba8>(), 4);
+    assert_eq!(std::mem::align_of::<packed_rgba8>(), 4);
+    assert_eq!(
+        packed_rgba8::from_rgba(0x11, 0x22, 0x33, 0x44).0,
+        0x44332211
+    );
+}
+
+#[test]
+fn packed_rgba8_round_trip_test() {
+    let color = packed_rgba8::from_rgba(12, 34, 56, 78);
+
+    assert_eq!(color.to_rgba(), [12, 34, 56, 78]);
+    assert_eq!(packed_rgba8::from(vec4::from(color)), color);
+}
+
+#[test]
+fn packed_rgba8_from_vec4_test() {
+    let color = packed_rgba8::from(vec4(1.0, 0.5, 0.0, 2.0));
+
+    assert_eq!(color.to_rgba(), [255, 128, 0, 255]);
+    assert_eq!(vec4::from(color), vec4(1.0, 128.0 / 255.0, 0.0, 1.0));
+}
@@ -0,0 +1,23 @@
+#[std140::repr_std140(binding = 2, set = 1)]
+struct Camera {
+    view: std140::mat::mat4x4,
+    projection: std140::mat::mat4x4,
+}
+
+#[std140::repr_std140(binding = 0)]
+struct Lighting {
+    ambient: std140::vec::vec4,
+}
+
+#[test]
+fn binding_and_set_test() {
+    assert_eq!(Camera::BINDING, 2);
+    assert_eq!(Camera::SET, 1);
+}
+
+#[test]
+fn binding_without_set_test() {
+    const BINDING: u32 = Lighting::BINDING;
+
+    assert_eq!(BINDING, 0);
+}
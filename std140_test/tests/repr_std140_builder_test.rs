@@ -0,0 +1,36 @@
+#[std140::repr_std140(builder)]
+struct Uniforms {
+    transform: std140::mat::mat4x4,
+    ambient_light_color: std140::vec::vec3,
+    intensity: std140::float,
+}
+
+#[std140::repr_std140(builder)]
+struct Attenuation(std140::float, std140::float);
+
+#[test]
+fn repr_std140_builder_test() {
+    let uniforms = Uniforms {
+        transform: std140::mat::mat4x4::zero(),
+        ambient_light_color: std140::vec::vec3::zero(),
+        intensity: std140::float(0.0),
+    }
+    .with_transform(std140::mat::mat4x4::identity())
+    .with_ambient_light_color(std140::vec::vec3(0.2, 0.2, 0.2));
+
+    assert_eq!(uniforms.transform, std140::mat::mat4x4::identity());
+    assert_eq!(
+        uniforms.ambient_light_color,
+        std140::vec::vec3(0.2, 0.2, 0.2)
+    );
+    assert_eq!(uniforms.intensity, std140::float(0.0));
+}
+
+#[test]
+fn repr_std140_builder_tuple_struct_test() {
+    let attenuation =
+        Attenuation(std140::float(0.0), std140::float(0.0)).with_1(std140::float(0.5));
+
+    assert_eq!(attenuation.0, std140::float(0.0));
+    assert_eq!(attenuation.1, std140::float(0.5));
+}
@@ -0,0 +1,57 @@
+use std140::Std140Struct;
+
+#[std140::repr_std140(builder, glsl, warn_padding)]
+struct Uniforms {
+    transform: std140::mat::mat4x4,
+    #[cfg(not(test))]
+    removed: std140::vec::vec4,
+    #[cfg(test)]
+    kept: std140::vec::vec4,
+    #[cfg(feature = "debug_uniforms")]
+    debug_color: std140::vec::vec4,
+    intensity: std140::float,
+}
+
+#[test]
+fn repr_std140_cfg_layout_test() {
+    let layout = Uniforms::STD140_LAYOUT;
+    let names: Vec<_> = layout.members.iter().map(|member| member.name).collect();
+
+    if cfg!(feature = "debug_uniforms") {
+        assert_eq!(names, ["transform", "kept", "debug_color", "intensity"]);
+        assert_eq!(layout.size, 112);
+        assert_eq!(layout.members[3].offset, 96);
+    } else {
+        assert_eq!(names, ["transform", "kept", "intensity"]);
+        assert_eq!(layout.size, 96);
+        assert_eq!(layout.members[2].offset, 80);
+    }
+}
+
+#[test]
+fn repr_std140_cfg_glsl_test() {
+    assert!(Uniforms::GLSL_DECL.contains("    vec4 kept;\n"));
+    assert!(!Uniforms::GLSL_DECL.contains("removed"));
+    assert_eq!(
+        Uniforms::GLSL_DECL.contains("    vec4 debug_color;\n"),
+        cfg!(feature = "debug_uniforms")
+    );
+}
+
+#[test]
+fn repr_std140_cfg_builder_test() {
+    let uniforms = Uniforms {
+        transform: std140::mat::mat4x4::identity(),
+        kept: std140::vec::vec4::zero(),
+        #[cfg(feature = "debug_uniforms")]
+        debug_color: std140::vec::vec4::zero(),
+        intensity: std140::float(0.0),
+    }
+    .with_kept(std140::vec::vec4(1.0, 1.0, 1.0, 1.0))
+    .with_intensity(std140::float(0.5));
+
+    assert_eq!(uniforms.transform, std140::mat::mat4x4::identity());
+    assert_eq!(uniforms.kept, std140::vec::vec4(1.0, 1.0, 1.0, 1.0));
+    assert_eq!(uniforms.intensity, std140::float(0.5));
+    assert_eq!(Uniforms::PADDING_REPORT.padding(), 12);
+}
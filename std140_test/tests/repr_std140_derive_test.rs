@@ -0,0 +1,43 @@
+#[std140::repr_std140]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct PointLight {
+    position: std140::vec::vec3,
+    intensity: std140::float,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[std140::repr_std140]
+struct SpotLight {
+    position: std140::vec::vec3,
+    direction: std140::vec::vec3,
+    angle: std140::float,
+}
+
+#[test]
+fn repr_std140_derive_after_test() {
+    let light = PointLight {
+        position: std140::vec::vec3(1.0, 2.0, 3.0),
+        intensity: std140::float(0.5),
+    };
+
+    let lights: std140::array::array<PointLight, 4> = std140::array![light; 4];
+
+    assert_eq!(lights.len(), 4);
+    assert_eq!(lights.last(), Some(&light));
+    assert_eq!(std::mem::align_of::<PointLight>(), 16);
+}
+
+#[test]
+fn repr_std140_derive_before_test() {
+    let light = SpotLight {
+        position: std140::vec::vec3(1.0, 2.0, 3.0),
+        direction: std140::vec::vec3(0.0, -1.0, 0.0),
+        angle: std140::float(0.5),
+    };
+
+    let lights: std140::array::array<SpotLight, 4> = std140::array![light; 4];
+
+    assert_eq!(lights.first(), Some(&light));
+    assert_eq!(std::mem::align_of::<SpotLight>(), 16);
+    assert_eq!(std::mem::size_of::<SpotLight>(), 48);
+}
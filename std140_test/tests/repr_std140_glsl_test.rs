@@ -0,0 +1,62 @@
+use std140::{array::array, vec::vec3 as Eye};
+
+#[std140::repr_std140]
+struct PointLight {
+    position: std140::vec::vec3,
+    intensity: std140::float,
+}
+
+#[std140::repr_std140(glsl)]
+struct Uniforms {
+    transform: std140::mat::mat4x4,
+    normal_matrix: std140::mat::mat3x4,
+    ambient_light_color: std140::vec::vec3,
+    lights: array<PointLight, 2>,
+    enabled: std140::boolean,
+    table: std140::array::array<array<std140::uint, 3>, { 1 + 1 }>,
+}
+
+#[std140::repr_std140(builder, glsl)]
+struct Color(std140::vec::vec4, std140::packed_rgba8);
+
+type Transform = std140::mat::mat4x4;
+
+#[std140::repr_std140(glsl)]
+struct r#Camera {
+    view: Transform,
+    eye: self::Eye,
+}
+
+#[test]
+fn repr_std140_glsl_decl_test() {
+    assert!(Uniforms::GLSL_DECL.contains("mat4 transform;"));
+    assert!(Uniforms::GLSL_DECL.contains("PointLight lights[2];"));
+    assert_eq!(
+        Uniforms::GLSL_DECL,
+        "layout(std140) uniform Uniforms {
+    mat4 transform;
+    mat3x4 normal_matrix;
+    vec3 ambient_light_color;
+    PointLight lights[2];
+    bool enabled;
+    uint table[2][3];
+};
+"
+    );
+}
+
+#[test]
+fn repr_std140_glsl_decl_tuple_struct_test() {
+    assert_eq!(
+        Color::GLSL_DECL,
+        "layout(std140) uniform Color {\n    vec4 _0;\n    uint _1;\n};\n"
+    );
+}
+
+#[test]
+fn repr_std140_glsl_decl_type_names_test() {
+    assert_eq!(
+        Camera::GLSL_DECL,
+        "layout(std140) uniform Camera {\n    mat4 view;\n    vec3 eye;\n};\n"
+    );
+}
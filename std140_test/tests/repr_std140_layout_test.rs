@@ -0,0 +1,83 @@
+use std140::{layout::MemberKind, Std140Struct};
+
+#[std140::repr_std140]
+struct PointLight {
+    position: std140::vec::vec3,
+    intensity: std140::float,
+}
+
+#[std140::repr_std140]
+struct Uniforms {
+    transform: std140::mat::mat4x4,
+    ambient_light_color: std140::vec::vec3,
+    lights: std140::array::array<PointLight, 2>,
+    light_count: std140::uint,
+}
+
+#[std140::repr_std140]
+struct Wrapper(PointLight);
+
+#[test]
+fn std140_layout_test() {
+    let layout = Uniforms::STD140_LAYOUT;
+
+    assert_eq!(layout.size, std::mem::size_of::<Uniforms>());
+    assert_eq!(layout.align, 16);
+    assert_eq!(layout.members.len(), 4);
+
+    let offsets: Vec<usize> = layout.members.iter().map(|member| member.offset).collect();
+    assert_eq!(offsets, vec![0, 64, 80, 144]);
+
+    let kinds: Vec<MemberKind> = layout.members.iter().map(|member| member.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            MemberKind::Matrix,
+            MemberKind::Vector,
+            MemberKind::Array,
+            MemberKind::Scalar
+        ]
+    );
+
+    assert_eq!(layout.members[0].name, "transform");
+    assert_eq!(layout.members[2].size, 64);
+    assert_eq!(layout.members[3].align, 4);
+}
+
+#[test]
+fn std140_layout_nested_test() {
+    let layout = PointLight::STD140_LAYOUT;
+
+    assert_eq!(layout.size, 32);
+    assert_eq!(layout.members[1].name, "intensity");
+    assert_eq!(layout.members[1].offset, 16);
+}
+
+#[test]
+fn std140_layout_tuple_struct_test() {
+    let layout = Wrapper::STD140_LAYOUT;
+
+    assert_eq!(layout.members[0].name, "0");
+    assert_eq!(layout.members[0].kind, MemberKind::Struct);
+    assert_eq!(layout.members[0].size, 32);
+}
+
+#[test]
+fn std140_layout_glsl_type_test() {
+    let members: Vec<_> = Uniforms::STD140_LAYOUT
+        .members
+        .iter()
+        .map(|member| (member.name, member.offset, member.size, member.glsl_type))
+        .collect();
+
+    assert_eq!(
+        members,
+        [
+            ("transform", 0, 64, "mat4"),
+            ("ambient_light_color", 64, 16, "vec3"),
+            ("lights", 80, 64, "PointLight[2]"),
+            ("light_count", 144, 4, "uint"),
+        ]
+    );
+    assert_eq!(Wrapper::STD140_LAYOUT.members[0].glsl_type, "PointLight");
+}
@@ -1,3 +1,5 @@
+use std140::Std140Struct;
+
 #[std140::repr_std140]
 struct PointLight {
     position: std140::vec::vec3,
@@ -13,4 +15,31 @@ struct Uniforms {
     lights: std140::array::array<PointLight, 2>,
 }
 
+#[test]
+fn repr_std140_layout_test() {
+    let uniforms = Uniforms {
+        transform: std140::mat::mat4x4::identity(),
+        ambient_light_color: std140::vec::vec3::zero(),
+        lights: std140::array![
+            PointLight {
+                position: std140::vec::vec3::zero(),
+                intensity: std140::float(0.5),
+                falloff: std140::float(1.0),
+            },
+            PointLight {
+                position: std140::vec::vec3::zero(),
+                intensity: std140::float(0.5),
+                falloff: std140::float(1.0),
+            },
+        ],
+    };
+
+    assert_eq!(std::mem::align_of_val(&uniforms), 16);
+    assert_eq!(std::mem::size_of_val(&uniforms), 144);
+    assert_eq!(Uniforms::STD140_LAYOUT.size, 144);
+    assert_eq!(Uniforms::STD140_LAYOUT.members[0].offset, 0);
+    assert_eq!(Uniforms::STD140_LAYOUT.members[2].offset, 80);
+    assert_eq!(uniforms.lights[1].intensity, std140::float(0.5));
+}
+
 fn main() {}
@@ -0,0 +1,87 @@
+use std140::{array::array, plain::PlainRepr, repr_std140};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Light {
+    position: [f32; 3],
+    color: [f32; 4],
+    intensity: f32,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Scene {
+    view: [[f32; 4]; 4],
+    lights: [Light; 2],
+    light_count: u32,
+    flags: [bool; 2],
+}
+
+#[repr_std140(mirror(Light))]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct LightUniforms {
+    position: std140::vec::vec3,
+    color: std140::vec::vec4,
+    intensity: std140::float,
+}
+
+#[repr_std140(mirror(Scene))]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct SceneUniforms {
+    view: std140::mat::mat4x4,
+    lights: array<LightUniforms, 2>,
+    light_count: std140::uint,
+    flags: std140::vec::bvec2,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Pair(i32, [u32; 2]);
+
+#[repr_std140(mirror(Pair))]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct PairUniforms(std140::int, std140::vec::uvec2);
+
+fn light(i: usize) -> Light {
+    Light {
+        position: [i as f32, 1.0, 2.0],
+        color: [1.0, 0.5, 0.25, 1.0],
+        intensity: 0.5 * i as f32,
+    }
+}
+
+#[test]
+fn repr_std140_mirror_test() {
+    let uniforms = LightUniforms::from(light(1));
+
+    assert_eq!(uniforms.position, std140::vec::vec3(1.0, 1.0, 2.0));
+    assert_eq!(uniforms.intensity, std140::float(0.5));
+    assert_eq!(Light::from(uniforms), light(1));
+}
+
+#[test]
+fn repr_std140_nested_mirror_test() {
+    let scene = Scene {
+        view: std140::mat::mat4x4::identity().into_plain(),
+        lights: [light(1), light(2)],
+        light_count: 2,
+        flags: [true, false],
+    };
+    let uniforms: SceneUniforms = scene.into();
+
+    assert_eq!(uniforms.view, std140::mat::mat4x4::identity());
+    assert_eq!(uniforms.lights[1].0, LightUniforms::from(light(2)));
+    assert_eq!(
+        uniforms.flags,
+        std140::vec::bvec2(std140::boolean::True, std140::boolean::False)
+    );
+    assert_eq!(Scene::from(uniforms), scene);
+}
+
+#[test]
+fn repr_std140_tuple_struct_mirror_test() {
+    let uniforms = PairUniforms::from(Pair(-1, [2, 3]));
+
+    assert_eq!(
+        uniforms,
+        PairUniforms(std140::int(-1), std140::vec::uvec2(2, 3))
+    );
+    assert_eq!(uniforms.into_plain(), Pair(-1, [2, 3]));
+}
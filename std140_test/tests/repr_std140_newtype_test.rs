@@ -0,0 +1,41 @@
+use std140::{layout::MemberKind, Std140Struct};
+
+#[std140::repr_std140]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Meters(std140::float);
+
+#[std140::repr_std140]
+struct Fog {
+    density: std140::float,
+    distance: Meters,
+    falloff: std140::float,
+}
+
+#[test]
+fn repr_std140_newtype_alignment_test() {
+    assert_eq!(std::mem::align_of::<Meters>(), 16);
+    assert_eq!(std::mem::size_of::<Meters>(), 16);
+}
+
+#[test]
+fn repr_std140_newtype_field_layout_test() {
+    let layout = Fog::STD140_LAYOUT;
+
+    assert_eq!(layout.size, 48);
+    assert_eq!(layout.align, 16);
+    assert_eq!(layout.members[0].offset, 0);
+    assert_eq!(layout.members[1].offset, 16);
+    assert_eq!(layout.members[1].size, 16);
+    assert_eq!(layout.members[1].kind, MemberKind::Struct);
+    assert_eq!(layout.members[2].offset, 32);
+
+    let fog = Fog {
+        density: std140::float(0.1),
+        distance: Meters(std140::float(100.0)),
+        falloff: std140::float(2.0),
+    };
+
+    assert_eq!(fog.distance, Meters(std140::float(100.0)));
+    assert_eq!(fog.density, std140::float(0.1));
+    assert_eq!(fog.falloff, std140::float(2.0));
+}
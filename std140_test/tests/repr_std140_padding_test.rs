@@ -0,0 +1,70 @@
+use std140::{
+    array::array,
+    float,
+    mat::{mat2x2, mat3x3, mat4x4},
+    uint,
+    vec::{vec2, vec3, vec4},
+    ReprStd140, Std140Struct,
+};
+
+#[std140::repr_std140(warn_padding)]
+struct Normals {
+    position: vec3,
+    normal: vec3,
+}
+
+#[std140::repr_std140(warn_padding)]
+struct Light {
+    intensity: float,
+    color: vec4,
+    range: float,
+}
+
+#[std140::repr_std140]
+struct Packed {
+    color: vec4,
+    transform: mat4x4,
+}
+
+#[test]
+fn std140_padding_test() {
+    assert_eq!(<float as ReprStd140>::STD140_PADDING, 0);
+    assert_eq!(<vec2 as ReprStd140>::STD140_PADDING, 0);
+    assert_eq!(<vec3 as ReprStd140>::STD140_PADDING, 4);
+    assert_eq!(<vec4 as ReprStd140>::STD140_PADDING, 0);
+    assert_eq!(<mat2x2 as ReprStd140>::STD140_PADDING, 16);
+    assert_eq!(<mat3x3 as ReprStd140>::STD140_PADDING, 12);
+    assert_eq!(<mat4x4 as ReprStd140>::STD140_PADDING, 0);
+    assert_eq!(<array<uint, 3> as ReprStd140>::STD140_PADDING, 36);
+    assert_eq!(<array<vec3, 2> as ReprStd140>::STD140_PADDING, 8);
+    assert_eq!(<Light as ReprStd140>::STD140_PADDING, 24);
+    assert_eq!(<array<Light, 2> as ReprStd140>::STD140_PADDING, 48);
+}
+
+#[test]
+fn padding_report_test() {
+    assert_eq!(Normals::PADDING_REPORT.padding(), 8);
+    assert_eq!(
+        Normals::PADDING_REPORT.to_string(),
+        "Normals: 8 of 32 bytes are padding
+  4 bytes inside `position`
+  4 bytes inside `normal`
+  consider ordering members from largest to smallest alignment, or combining scalars and small vectors into a vec4
+"
+    );
+
+    assert_eq!(Light::PADDING_REPORT.padding(), 24);
+    assert_eq!(Light::STD140_LAYOUT.padding_after(0), 12);
+    assert_eq!(Light::STD140_LAYOUT.padding_after(2), 12);
+    assert!(Light::PADDING_REPORT
+        .to_string()
+        .contains("  12 bytes after `intensity`\n"));
+}
+
+#[test]
+fn padding_report_without_padding_test() {
+    let report = std140::layout::PaddingReport::new("Packed", Packed::STD140_LAYOUT);
+
+    assert_eq!(report.padding(), 0);
+    assert_eq!(report.to_string(), "Packed: 0 of 80 bytes are padding\n");
+}
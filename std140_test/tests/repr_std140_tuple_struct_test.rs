@@ -0,0 +1,28 @@
+#[std140::repr_std140]
+struct Color(std140::vec::vec4);
+
+#[std140::repr_std140]
+struct Attenuation(std140::float, std140::float);
+
+#[std140::repr_std140]
+struct Material {
+    color: Color,
+    attenuation: Attenuation,
+    shininess: std140::float,
+}
+
+#[test]
+fn repr_std140_tuple_struct_test() {
+    let material = Material {
+        color: Color(std140::vec::vec4(1.0, 0.5, 0.25, 1.0)),
+        attenuation: Attenuation(std140::float(1.0), std140::float(0.5)),
+        shininess: std140::float(32.0),
+    };
+
+    assert_eq!(std::mem::align_of::<Attenuation>(), 16);
+    assert_eq!(std::mem::size_of::<Attenuation>(), 16);
+    assert_eq!(std::mem::size_of::<Material>(), 48);
+    assert_eq!(material.color.0, std140::vec::vec4(1.0, 0.5, 0.25, 1.0));
+    assert_eq!(material.attenuation.1, std140::float(0.5));
+    assert_eq!(material.shininess, std140::float(32.0));
+}
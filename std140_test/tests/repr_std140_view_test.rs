@@ -0,0 +1,73 @@
+use std140::{array::array, plain::PlainRepr, repr_std140};
+
+#[repr_std140(view = LightView)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct LightUniforms {
+    position: std140::vec::vec3,
+    color: std140::vec::vec4,
+    intensity: std140::float,
+    enabled: std140::boolean,
+}
+
+#[repr_std140(view = SceneView, binding = 1)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct SceneUniforms {
+    view: std140::mat::mat4x4,
+    lights: array<LightUniforms, 2>,
+    light_count: std140::uint,
+}
+
+#[repr_std140(view = PairView)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct PairUniforms(std140::int, std140::vec::uvec2);
+
+fn light(i: usize) -> LightView {
+    LightView {
+        position: [i as f32, 1.0, 2.0],
+        color: [1.0, 0.5, 0.25, 1.0],
+        intensity: 0.5 * i as f32,
+        enabled: i % 2 == 0,
+    }
+}
+
+#[test]
+fn repr_std140_view_round_trip_test() {
+    let view = light(2);
+    let uniforms = LightUniforms::from(view);
+
+    assert_eq!(uniforms.position, std140::vec::vec3(2.0, 1.0, 2.0));
+    assert_eq!(uniforms.intensity, std140::float(1.0));
+    assert_eq!(uniforms.enabled, std140::boolean::True);
+    assert_eq!(LightView::from(uniforms), view);
+}
+
+#[test]
+fn repr_std140_view_nested_test() {
+    let scene = SceneView {
+        view: [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, -5.0, 1.0],
+        ],
+        lights: [light(0), light(1)],
+        light_count: 2,
+    };
+
+    let uniforms = SceneUniforms::from(scene);
+
+    assert_eq!(uniforms.view, std140::mat::mat4x4::from_plain(scene.view));
+    assert_eq!(*uniforms.lights[1], LightUniforms::from(light(1)));
+    assert_eq!(uniforms.into_plain(), scene);
+    assert_eq!(SceneUniforms::BINDING, 1);
+}
+
+#[test]
+fn repr_std140_view_tuple_struct_test() {
+    let pair = PairView(-3, [1, 2]);
+    let uniforms = PairUniforms::from(pair);
+
+    assert_eq!(uniforms.0, std140::int(-3));
+    assert_eq!(uniforms.1, std140::vec::uvec2(1, 2));
+    assert_eq!(PairView::from(uniforms), pair);
+}
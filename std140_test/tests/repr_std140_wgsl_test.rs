@@ -0,0 +1,46 @@
+use std140::{array::array, repr_std140};
+
+#[repr_std140]
+struct PointLight {
+    position: std140::vec::vec3,
+    intensity: std140::float,
+}
+
+#[repr_std140(wgsl)]
+struct Uniforms {
+    transform: std140::mat::mat4x4,
+    tint: std140::vec::vec3,
+    exposure: std140::float,
+    flags: std140::vec::bvec2,
+    lights: array<PointLight, 2>,
+    enabled: std140::boolean,
+}
+
+#[repr_std140(wgsl)]
+struct Tuple(std140::vec::ivec4, array<std140::vec::uvec3, 3>);
+
+#[test]
+fn repr_std140_wgsl_definition_test() {
+    assert_eq!(
+        Uniforms::wgsl_definition(),
+        "struct Uniforms {\n\
+         \x20   @align(16) @size(64) transform: mat4x4<f32>,\n\
+         \x20   @align(16) @size(16) tint: vec3<f32>,\n\
+         \x20   @align(4) @size(8) exposure: f32,\n\
+         \x20   @align(8) @size(8) flags: vec2<u32>,\n\
+         \x20   @align(16) @size(64) lights: array<PointLight, 2>,\n\
+         \x20   @align(4) @size(16) enabled: u32,\n\
+         }\n"
+    );
+}
+
+#[test]
+fn repr_std140_wgsl_definition_tuple_struct_test() {
+    assert_eq!(
+        Tuple::wgsl_definition(),
+        "struct Tuple {\n\
+         \x20   @align(16) @size(16) _0: vec4<i32>,\n\
+         \x20   @align(16) @size(48) _1: array<vec3<u32>, 3>,\n\
+         }\n"
+    );
+}
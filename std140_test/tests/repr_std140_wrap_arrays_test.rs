@@ -0,0 +1,39 @@
+use std140::{layout::MemberKind, Std140Struct};
+
+const CASCADES: usize = 3;
+
+#[std140::repr_std140(wrap_arrays, glsl)]
+struct Shadows {
+    weights: [std140::float; 4],
+    cascades: [std140::mat::mat4x4; CASCADES],
+    grid: [[std140::uint; 2]; 3],
+    bias: std140::float,
+}
+
+#[test]
+fn repr_std140_wrap_arrays_layout_test() {
+    let layout = Shadows::STD140_LAYOUT;
+
+    assert_eq!(layout.members[0].kind, MemberKind::Array);
+    assert_eq!(layout.members[0].size, 4 * 16);
+    assert_eq!(layout.members[1].offset, 64);
+    assert_eq!(layout.members[2].offset, 64 + 3 * 64);
+    assert_eq!(layout.members[2].size, 3 * 2 * 16);
+    assert_eq!(layout.members[3].offset, 64 + 3 * 64 + 96);
+}
+
+#[test]
+fn repr_std140_wrap_arrays_value_test() {
+    let shadows = Shadows {
+        weights: std140::array![std140::float(0.25); 4],
+        cascades: std140::array![std140::mat::mat4x4::identity(); CASCADES],
+        grid: std140::array![std140::array![std140::uint(1); 2]; 3],
+        bias: std140::float(0.01),
+    };
+
+    assert_eq!(shadows.weights[3].0, std140::float(0.25));
+    assert_eq!(shadows.grid[2][1].0, std140::uint(1));
+    assert!(Shadows::GLSL_DECL.contains("    float weights[4];\n"));
+    assert!(Shadows::GLSL_DECL.contains("    mat4 cascades[3];\n"));
+    assert!(Shadows::GLSL_DECL.contains("    uint grid[3][2];\n"));
+}
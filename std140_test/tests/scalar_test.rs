@@ -0,0 +1,114 @@
+use std140::float;
+
+#[test]
+fn float_bits_test() {
+    let nan = float::from_bits(0x7fc0_1234);
+
+    assert!(nan.0.is_nan());
+    assert_eq!(nan.to_bits(), 0x7fc0_1234);
+    assert_eq!(
+        float::from_bits(float(-0.0).to_bits()).to_bits(),
+        0x8000_0000
+    );
+}
+
+#[test]
+fn scalar_primitive_eq_test() {
+    assert!(float(1.0) == 1.0);
+    assert!(1.0 == float(1.0));
+    assert!(float(1.0) != 2.0);
+    assert!(std140::int(-1) == -1);
+    assert!(-1 == std140::int(-1));
+    assert!(std140::uint(1) == 1);
+    assert!(2 != std140::uint(1));
+}
+
+#[test]
+fn scalar_from_primitive_test() {
+    let value: float = 0.5.into();
+    assert_eq!(value, float(0.5));
+
+    assert_eq!(std140::int::from(-3), std140::int(-3));
+    assert_eq!(std140::uint::from(3), std140::uint(3));
+}
+
+#[test]
+fn scalar_into_primitive_test() {
+    let value: f32 = float(0.5).into();
+    assert_eq!(value, 0.5);
+    assert_eq!(float::from(f32::from(float(-1.5))), float(-1.5));
+
+    let value: i32 = std140::int(-3).into();
+    assert_eq!(value, -3);
+    assert_eq!(std140::int::from(i32::from(std140::int(7))), std140::int(7));
+
+    let value: u32 = std140::uint(3).into();
+    assert_eq!(value, 3);
+    assert_eq!(
+        std140::uint::from(u32::from(std140::uint(u32::MAX))),
+        std140::uint(u32::MAX)
+    );
+}
+
+#[test]
+fn scalar_arithmetic_test() {
+    assert_eq!(float(2.0) + float(3.0), float(5.0));
+    assert_eq!(float(2.0) - float(3.0), float(-1.0));
+    assert_eq!(float(2.0) * float(3.0), float(6.0));
+    assert_eq!(float(3.0) / float(2.0), float(1.5));
+
+    assert_eq!(std140::int(-7) / std140::int(2), std140::int(-3));
+    assert_eq!(std140::uint(7) - std140::uint(2), std140::uint(5));
+
+    let mut value = std140::uint(2);
+    value += std140::uint(3);
+    value *= std140::uint(4);
+    value -= std140::uint(1);
+    value /= std140::uint(19);
+
+    assert_eq!(value, std140::uint(1));
+}
+
+#[test]
+#[should_panic]
+fn scalar_division_by_zero_test() {
+    let _ = std140::int(1) / std140::int(0);
+}
+
+#[test]
+fn scalar_sum_product_test() {
+    let values = [float(1.0), float(2.0), float(3.0)];
+
+    assert_eq!(values.iter().sum::<float>(), float(6.0));
+    assert_eq!(values.into_iter().product::<float>(), float(6.0));
+    assert_eq!(
+        [std140::uint(2), std140::uint(5)]
+            .iter()
+            .product::<std140::uint>(),
+        std140::uint(10)
+    );
+}
+
+#[test]
+fn float_clamp_test() {
+    assert_eq!(float(1.5).clamp(0.0, 1.0), float(1.0));
+    assert_eq!(float(-3.0).clamp(-2.0, 2.0), float(-2.0));
+    assert_eq!(float(0.25).clamp(0.0, 1.0), float(0.25));
+    assert_eq!(float(1.5).saturate(), float(1.0));
+    assert_eq!(float(-0.5).saturate(), float(0.0));
+    assert!(float(f32::NAN).saturate().0.is_nan());
+}
+
+#[test]
+fn boolean_display_test() {
+    use std140::boolean;
+
+    assert_eq!(boolean::True.as_str(), "true");
+    assert_eq!(boolean::False.as_str(), "false");
+    assert_eq!(boolean::True.to_string(), "true");
+    assert_eq!(
+        format!("bool flag = {};", boolean::False),
+        "bool flag = false;"
+    );
+    assert_eq!(format!("{:?}", boolean::True), "True");
+}
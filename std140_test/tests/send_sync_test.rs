@@ -0,0 +1,64 @@
+use std140::{
+    array::{array, AlignmentedElement},
+    boolean, float, int,
+    layout::{Layout, Member, MemberKind},
+    mat::{mat2x2, mat2x3, mat2x4, mat3x2, mat3x3, mat3x4, mat4x2, mat4x3, mat4x4},
+    packed_rgba8, uint,
+    unbounded_array::unbounded_array,
+    vec::{bvec2, bvec3, bvec4, ivec2, ivec3, ivec4, uvec2, uvec3, uvec4, vec2, vec3, vec4},
+    Std140OffsetBuilder,
+};
+
+#[std140::repr_std140]
+struct Uniforms {
+    transform: mat4x4,
+    color: vec4,
+}
+
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<float>();
+    assert_send_sync::<int>();
+    assert_send_sync::<uint>();
+    assert_send_sync::<boolean>();
+    assert_send_sync::<packed_rgba8>();
+
+    assert_send_sync::<vec2>();
+    assert_send_sync::<vec3>();
+    assert_send_sync::<vec4>();
+    assert_send_sync::<ivec2>();
+    assert_send_sync::<ivec3>();
+    assert_send_sync::<ivec4>();
+    assert_send_sync::<uvec2>();
+    assert_send_sync::<uvec3>();
+    assert_send_sync::<uvec4>();
+    assert_send_sync::<bvec2>();
+    assert_send_sync::<bvec3>();
+    assert_send_sync::<bvec4>();
+
+    assert_send_sync::<mat2x2>();
+    assert_send_sync::<mat2x3>();
+    assert_send_sync::<mat2x4>();
+    assert_send_sync::<mat3x2>();
+    assert_send_sync::<mat3x3>();
+    assert_send_sync::<mat3x4>();
+    assert_send_sync::<mat4x2>();
+    assert_send_sync::<mat4x3>();
+    assert_send_sync::<mat4x4>();
+
+    assert_send_sync::<AlignmentedElement<float>>();
+    assert_send_sync::<array<vec3, 4>>();
+    assert_send_sync::<array<array<mat4x4, 2>, 2>>();
+    assert_send_sync::<std140::array::IntoIter<vec3, 4>>();
+    assert_send_sync::<std140::array::Iter<'static, vec3>>();
+    assert_send_sync::<unbounded_array<vec4>>();
+
+    assert_send_sync::<Layout>();
+    assert_send_sync::<Member>();
+    assert_send_sync::<MemberKind>();
+    assert_send_sync::<Std140OffsetBuilder>();
+
+    assert_send_sync::<Uniforms>();
+    assert_send_sync::<array<Uniforms, 2>>();
+};
@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+use std140::{
+    boolean, float,
+    mat::mat4x4,
+    vec::{bvec2, vec3},
+};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Config {
+    #[serde(with = "std140::serde::row_major")]
+    transform: mat4x4,
+    offset: vec3,
+    scale: float,
+    flags: bvec2,
+}
+
+fn translation() -> mat4x4 {
+    std140::mat4x4(
+        std140::vec::vec4(1.0, 0.0, 0.0, 0.0),
+        std140::vec::vec4(0.0, 1.0, 0.0, 0.0),
+        std140::vec::vec4(0.0, 0.0, 1.0, 0.0),
+        std140::vec::vec4(5.0, 6.0, 7.0, 1.0),
+    )
+}
+
+#[test]
+fn serde_scalar_vector_test() {
+    assert_eq!(serde_json::to_string(&float(0.5)).unwrap(), "0.5");
+    assert_eq!(
+        serde_json::to_string(&vec3(1.0, 2.0, 3.0)).unwrap(),
+        "[1.0,2.0,3.0]"
+    );
+    assert_eq!(
+        serde_json::to_string(&bvec2(boolean::True, boolean::False)).unwrap(),
+        "[true,false]"
+    );
+    assert_eq!(
+        serde_json::from_str::<vec3>("[1.0,2.0,3.0]").unwrap(),
+        vec3(1.0, 2.0, 3.0)
+    );
+}
+
+#[test]
+fn serde_matrix_column_major_test() {
+    let json = serde_json::to_string(&translation()).unwrap();
+
+    assert_eq!(
+        json,
+        "[[1.0,0.0,0.0,0.0],[0.0,1.0,0.0,0.0],[0.0,0.0,1.0,0.0],[5.0,6.0,7.0,1.0]]"
+    );
+    assert_eq!(
+        serde_json::from_str::<mat4x4>(&json).unwrap(),
+        translation()
+    );
+}
+
+#[test]
+fn serde_matrix_row_major_test() {
+    let config = Config {
+        transform: translation(),
+        offset: vec3(0.0, 1.0, 0.0),
+        scale: float(2.0),
+        flags: bvec2(boolean::False, boolean::True),
+    };
+
+    let json = serde_json::to_value(&config).unwrap();
+
+    assert_eq!(
+        json["transform"],
+        serde_json::json!([
+            [1.0, 0.0, 0.0, 5.0],
+            [0.0, 1.0, 0.0, 6.0],
+            [0.0, 0.0, 1.0, 7.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    );
+
+    let loaded: Config = serde_json::from_value(json).unwrap();
+
+    assert_eq!(loaded, config);
+    assert_eq!(loaded.transform[3], std140::vec::vec4(5.0, 6.0, 7.0, 1.0));
+}
+
+#[test]
+fn serde_flat_test() {
+    use std140::serde::Flat;
+
+    let json = serde_json::to_value(Flat(mat4x4::identity())).unwrap();
+    let numbers = json.as_array().unwrap();
+
+    assert_eq!(numbers.len(), 16);
+    assert!(numbers.iter().all(|number| number.is_number()));
+    assert_eq!(numbers[0], 1.0);
+    assert_eq!(numbers[1], 0.0);
+    assert_eq!(numbers[5], 1.0);
+
+    let json = serde_json::to_string(&Flat(translation())).unwrap();
+    assert_eq!(
+        json,
+        "[1.0,0.0,0.0,0.0,0.0,1.0,0.0,0.0,0.0,0.0,1.0,0.0,5.0,6.0,7.0,1.0]"
+    );
+
+    let Flat(loaded) = serde_json::from_str::<Flat<mat4x4>>(&json).unwrap();
+    assert_eq!(loaded, translation());
+
+    assert_eq!(
+        serde_json::to_string(&Flat(vec3(1.0, 2.0, 3.0))).unwrap(),
+        "[1.0,2.0,3.0]"
+    );
+    assert!(serde_json::from_str::<Flat<mat4x4>>("[1.0,2.0]").is_err());
+}
+
+#[test]
+fn serde_unbounded_array_test() {
+    use std140::{unbounded_array::unbounded_array, vec::vec2};
+
+    let points = std140::unbounded_array![vec2, vec2(0.0, 1.0), vec2(2.0, 3.0), vec2(4.0, 5.0)];
+
+    let json = serde_json::to_string(&points).unwrap();
+    assert_eq!(json, "[[0.0,1.0],[2.0,3.0],[4.0,5.0]]");
+
+    let loaded: unbounded_array<vec2> = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded, points);
+
+    let empty: unbounded_array<vec2> = serde_json::from_str("[]").unwrap();
+    assert!(empty.is_empty());
+}
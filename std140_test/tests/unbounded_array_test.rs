@@ -0,0 +1,207 @@
+use std140::{unbounded_array::unbounded_array, vec::vec4};
+
+#[test]
+fn unbounded_array_from_slice_test() {
+    let colors: Vec<vec4> = (0..5).map(|i| vec4(i as f32, 0.5, 0.25, 1.0)).collect();
+
+    let array = unbounded_array::from_slice(&colors);
+
+    assert_eq!(array.len(), 5);
+
+    // SAFETY: `vec4` has no padding and the elements of the array are stored contiguously.
+    let bytes =
+        unsafe { std::slice::from_raw_parts(array.as_ptr() as *const u8, array.len() * 16) };
+
+    for (i, color) in colors.iter().enumerate() {
+        let element = &bytes[i * 16..(i + 1) * 16];
+
+        assert_eq!(element[0..4], color.0.to_ne_bytes());
+        assert_eq!(element[12..16], color.3.to_ne_bytes());
+    }
+}
+
+#[test]
+fn unbounded_array_try_into_array_test() {
+    use std140::{array::array, uint};
+
+    let values = std140::unbounded_array![uint, uint(1), uint(2), uint(3)];
+    let values: array<uint, 3> = values.try_into().unwrap();
+    assert_eq!(values, std140::array![uint(1), uint(2), uint(3)]);
+
+    let values = std140::unbounded_array![uint, uint(1), uint(2)];
+    let error = array::<uint, 3>::try_from(values).unwrap_err();
+    assert_eq!(error.len(), 2);
+    assert_eq!(error.last(), Some(&uint(2)));
+}
+
+#[test]
+fn unbounded_array_from_array_test() {
+    use std140::{
+        array::{array, AlignmentedElement},
+        uint,
+    };
+
+    let defaults: array<uint, 2> = std140::array![uint(1), uint(2)];
+    let mut values: unbounded_array<uint> = defaults.into();
+
+    values.push(AlignmentedElement::new(uint(3)));
+
+    assert_eq!(values.len(), 3);
+    assert_eq!(values.first(), Some(&uint(1)));
+    assert_eq!(values.last(), Some(&uint(3)));
+}
+
+#[test]
+fn unbounded_array_eq_test() {
+    use std140::uint;
+
+    let a = std140::unbounded_array![uint, uint(1), uint(2)];
+    let b = std140::unbounded_array![uint, uint(1), uint(2)];
+    let longer = std140::unbounded_array![uint, uint(1), uint(2), uint(3)];
+    let different = std140::unbounded_array![uint, uint(1), uint(3)];
+
+    assert_eq!(a, b);
+    assert_ne!(a, longer);
+    assert_ne!(longer, a);
+    assert_ne!(a, different);
+}
+
+#[test]
+fn unbounded_array_debug_test() {
+    use std140::{uint, vec::vec2};
+
+    let values = std140::unbounded_array![uint, uint(7), uint(9)];
+
+    assert_eq!(format!("{:?}", values), "[uint(7), uint(9)]");
+    assert_eq!(
+        format!("{:#?}", values),
+        "[\n    [0]: uint(\n        7,\n    ),\n    [1]: uint(\n        9,\n    ),\n]"
+    );
+
+    let empty = unbounded_array::<vec2>::new(Vec::new());
+    assert_eq!(format!("{:#?}", empty), "[]");
+}
+
+#[test]
+fn unbounded_array_to_std140_bytes_test() {
+    let stride = std::mem::size_of::<std140::array::AlignmentedElement<std140::vec::vec2>>();
+    let positions: unbounded_array<std140::vec::vec2> = unbounded_array::from_slice(&[
+        std140::vec::vec2(0.0, 1.0),
+        std140::vec::vec2(2.0, 3.0),
+        std140::vec::vec2(4.0, 5.0),
+    ]);
+    let bytes = positions.to_std140_bytes();
+
+    assert_eq!(stride, 16);
+    assert_eq!(bytes.len(), positions.len() * stride);
+    assert_eq!(bytes[2 * stride + 4..2 * stride + 8], 5.0f32.to_ne_bytes());
+    assert_eq!(bytes[2 * stride + 8..], [0; 8]);
+    assert!(unbounded_array::<vec4>::new(Vec::new())
+        .to_std140_bytes()
+        .is_empty());
+}
+
+#[test]
+fn unbounded_array_from_fn_test() {
+    let values = unbounded_array::from_fn(5, |i| std140::uint(i as u32));
+
+    assert_eq!(values.len(), 5);
+    for (i, value) in values.iter().enumerate() {
+        assert_eq!(value.0, std140::uint(i as u32));
+    }
+    assert!(unbounded_array::from_fn(0, |_| std140::float(1.0)).is_empty());
+}
+
+#[test]
+fn unbounded_array_par_from_fn_test() {
+    let transform = |i: usize| {
+        let angle = i as f32 * 0.001;
+        std140::mat::mat2x2::from_columns([
+            std140::vec::vec2(angle.cos(), angle.sin()),
+            std140::vec::vec2(-angle.sin(), angle.cos()),
+        ])
+    };
+
+    let parallel = unbounded_array::par_from_fn(10_000, transform);
+    let sequential = unbounded_array::from_fn(10_000, transform);
+
+    assert!(parallel == sequential);
+    assert!(unbounded_array::par_from_fn(0, transform).is_empty());
+}
+
+#[test]
+fn unbounded_array_split_at_test() {
+    let ids = unbounded_array::from_fn(8, |i| std140::uint(i as u32));
+    let (first, second) = ids.split_at(4);
+
+    assert!(first.iter().map(|id| id.0).eq((0..4).map(std140::uint)));
+    assert!(second.iter().map(|id| id.0).eq((4..8).map(std140::uint)));
+}
+
+#[test]
+fn unbounded_array_range_index_test() {
+    let mut ids = unbounded_array::from_fn(20, |i| std140::uint(i as u32));
+
+    let page = &ids[10..20];
+    assert_eq!(page.len(), 10);
+    assert_eq!(page[0].0, std140::uint(10));
+    assert_eq!(ids[..5].len(), 5);
+    assert_eq!(ids[15..].len(), 5);
+    assert_eq!(ids[..].len(), 20);
+    assert_eq!(ids[3].0, std140::uint(3));
+
+    ids[4].0 = std140::uint(40);
+    assert!(ids
+        .sub(3..6)
+        .eq(&[std140::uint(3), std140::uint(40), std140::uint(5)]));
+    assert_eq!(ids.sub(..).count(), 20);
+}
+
+#[test]
+#[should_panic(expected = "range end index 21 out of range for slice of length 20")]
+fn unbounded_array_range_index_out_of_bounds_test() {
+    let ids = unbounded_array::from_fn(20, |i| std140::uint(i as u32));
+    let _ = &ids[10..21];
+}
+
+#[test]
+fn unbounded_array_sort_test() {
+    use std140::uint;
+
+    let mut materials = std140::unbounded_array![uint, uint(3), uint(1), uint(2), uint(1)];
+    materials.sort();
+    assert_eq!(
+        materials,
+        std140::unbounded_array![uint, uint(1), uint(1), uint(2), uint(3)]
+    );
+
+    let mut instances = std140::unbounded_array![
+        vec4,
+        vec4(0.0, 0.0, 0.0, 2.0),
+        vec4(1.0, 0.0, 0.0, 1.0),
+        vec4(2.0, 0.0, 0.0, 2.0)
+    ];
+    instances.sort_by(|a, b| a.3.total_cmp(&b.3));
+    assert_eq!(instances.first(), Some(&vec4(1.0, 0.0, 0.0, 1.0)));
+    assert_eq!(instances[1].0, vec4(0.0, 0.0, 0.0, 2.0));
+    assert_eq!(instances.last(), Some(&vec4(2.0, 0.0, 0.0, 2.0)));
+}
+
+#[test]
+fn unbounded_array_dedup_test() {
+    use std140::uint;
+
+    let mut values = std140::unbounded_array![uint, uint(1), uint(1), uint(2), uint(3), uint(3)];
+    values.dedup();
+    assert_eq!(
+        values,
+        std140::unbounded_array![uint, uint(1), uint(2), uint(3)]
+    );
+
+    let mut values = std140::unbounded_array![uint, uint(1), uint(3), uint(2), uint(4), uint(5)];
+    values.dedup_by_key(|value| value.0 % 2);
+    assert_eq!(
+        values,
+        std140::unbounded_array![uint, uint(1), uint(2), uint(5)]
+    );
+}
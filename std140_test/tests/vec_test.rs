@@ -0,0 +1,314 @@
+use std140::{
+    boolean,
+    vec::{
+        bvec2, bvec2_from_bits, bvec3, bvec3_from_bits, bvec4, bvec4_from_bits, ivec3, ivec4,
+        uvec2, vec2, vec3,
+    },
+};
+
+#[test]
+fn vec_map_test() {
+    assert_eq!(vec3(1.0, 2.0, 3.0).map(|x| x * x), vec3(1.0, 4.0, 9.0));
+    assert_eq!(ivec4(1, -2, 3, -4).map(i32::abs), ivec4(1, 2, 3, 4));
+    assert_eq!(
+        bvec2(boolean::True, boolean::False).map(|b| boolean::from(b == boolean::False)),
+        bvec2(boolean::False, boolean::True)
+    );
+}
+
+#[test]
+fn vec_reductions_test() {
+    let v = vec3(1.0, 2.0, 3.0);
+
+    assert_eq!(v.sum(), 6.0);
+    assert_eq!(v.product(), 6.0);
+    assert_eq!(v.min_element(), 1.0);
+    assert_eq!(v.max_element(), 3.0);
+
+    assert_eq!(ivec4(1, -2, 3, -4).sum(), -2);
+    assert_eq!(ivec4(1, -2, 3, -4).min_element(), -4);
+    assert_eq!(uvec2(3, 4).product(), 12);
+    assert_eq!(uvec2(3, 4).max_element(), 4);
+}
+
+#[test]
+fn vec_neg_test() {
+    assert_eq!(-vec2(1.0, -2.0), vec2(-1.0, 2.0));
+    assert_eq!(-ivec3(1, -2, 0), ivec3(-1, 2, 0));
+}
+
+#[test]
+fn vec_bits_test() {
+    let bits = [0x3f80_0000, 0x7fc0_1234, 0x8000_0000];
+    let v = std140::vec::vec3::from_bits(bits);
+
+    assert_eq!(v.0, 1.0);
+    assert!(v.1.is_nan());
+    assert_eq!(v.to_bits(), bits);
+}
+
+#[test]
+fn vec3_bytes_test() {
+    let v = vec3(1.0, 2.0, 3.0);
+
+    assert_eq!(v.to_std140_bytes()[..12], *v.meaningful_bytes());
+    assert_eq!(v.to_std140_bytes()[12..], [0; 4]);
+    assert_eq!(v.meaningful_bytes().len(), 12);
+    assert_eq!(v.meaningful_bytes()[4..8], 2.0f32.to_ne_bytes());
+    assert_eq!(ivec3(1, 2, 3).meaningful_bytes()[8..], 3i32.to_ne_bytes());
+}
+
+#[test]
+fn vec_arithmetic_test() {
+    assert_eq!(vec2(1.0, 2.0) + vec2(3.0, 4.0), vec2(4.0, 6.0));
+    assert_eq!(ivec3(1, 2, 3) - ivec3(3, 2, 1), ivec3(-2, 0, 2));
+    assert_eq!(uvec2(2, 3) * 2, uvec2(4, 6));
+    assert_eq!(vec3(2.0, 4.0, 6.0) / 2.0, vec3(1.0, 2.0, 3.0));
+
+    let mut v = vec2(1.0, 1.0);
+    v += vec2(1.0, 2.0);
+    v *= 2.0;
+    assert_eq!(v, vec2(4.0, 6.0));
+}
+
+#[test]
+fn vec_sum_product_test() {
+    let vectors = [vec2(1.0, 2.0), vec2(3.0, 4.0), vec2(5.0, 6.0)];
+
+    assert_eq!(vectors.iter().sum::<vec2>(), vec2(9.0, 12.0));
+    assert_eq!(vectors.into_iter().product::<vec2>(), vec2(15.0, 48.0));
+    assert_eq!(std::iter::empty::<vec2>().sum::<vec2>(), vec2::zero());
+}
+
+#[test]
+fn vec_lerp_test() {
+    let a = vec3(0.0, 2.0, -4.0);
+    let b = vec3(2.0, 4.0, 4.0);
+
+    assert_eq!(a.lerp(b, 0.0), a);
+    assert_eq!(a.lerp(b, 1.0), b);
+    assert_eq!(a.lerp(b, 0.5), vec3(1.0, 3.0, 0.0));
+    assert_eq!(a.lerp(b, 2.0), vec3(4.0, 6.0, 12.0));
+}
+
+#[test]
+fn bvec_logic_test() {
+    let v = bvec3(boolean::True, boolean::True, boolean::False);
+
+    assert!(!v.all());
+    assert!(v.any());
+    assert_eq!(!v, bvec3(boolean::False, boolean::False, boolean::True));
+    assert!((!bvec3::zero()).all());
+    assert!(!bvec2::zero().any());
+    assert_eq!(!boolean::True, boolean::False);
+}
+
+#[test]
+fn bvec_bits_test() {
+    let v = bvec4_from_bits(0b1010);
+    assert_eq!(
+        v,
+        bvec4(boolean::False, boolean::True, boolean::False, boolean::True)
+    );
+    assert_eq!(v.to_bits(), 0b1010);
+
+    assert_eq!(bvec3_from_bits(0b1111).to_bits(), 0b111);
+    assert_eq!(bvec2_from_bits(0b01), bvec2(boolean::True, boolean::False));
+}
+
+#[test]
+fn vec_float_checks_test() {
+    assert!(vec3(1.0, 2.0, 3.0).is_finite());
+    assert!(!vec3(1.0, f32::NEG_INFINITY, 3.0).is_finite());
+    assert!(!vec3(1.0, f32::NEG_INFINITY, 3.0).is_nan());
+    assert!(vec2(f32::NAN, 0.0).is_nan());
+    assert!(!vec2(f32::NAN, 0.0).is_finite());
+}
+
+#[test]
+fn vec_abs_test() {
+    assert_eq!(vec3(-1.0, 0.0, 2.5).abs(), vec3(1.0, 0.0, 2.5));
+}
+
+#[test]
+fn vec_dot_test() {
+    assert_eq!(vec3(1.0, 2.0, 3.0).dot(vec3(4.0, -5.0, 6.0)), 12.0);
+}
+
+#[test]
+fn vec_reflect_test() {
+    // A ray travelling down and to the right bounces off the floor.
+    let incident = vec3(1.0, -1.0, 0.0);
+    let normal = vec3(0.0, 1.0, 0.0);
+
+    assert_eq!(incident.reflect(normal), vec3(1.0, 1.0, 0.0));
+    assert_eq!(vec2(0.0, -2.0).reflect(vec2(0.0, 1.0)), vec2(0.0, 2.0));
+}
+
+#[test]
+fn vec_refract_test() {
+    let normal = vec3(0.0, 1.0, 0.0);
+
+    // Equal indices of refraction leave the direction unchanged.
+    let incident = vec3(
+        std::f32::consts::FRAC_1_SQRT_2,
+        -std::f32::consts::FRAC_1_SQRT_2,
+        0.0,
+    );
+    let refracted = incident.refract(normal, 1.0);
+    assert!((refracted - incident).abs().max_element() < 1e-6);
+
+    // Perpendicular incidence is never bent.
+    assert_eq!(
+        vec3(0.0, -1.0, 0.0).refract(normal, 0.75),
+        vec3(0.0, -1.0, 0.0)
+    );
+
+    // Total internal reflection yields a zero vector.
+    assert_eq!(incident.refract(normal, 1.5), vec3::zero());
+}
+
+#[test]
+fn vec_length_distance_test() {
+    assert_eq!(vec2(3.0, 4.0).length(), 5.0);
+    assert_eq!(vec3(1.0, 2.0, 3.0).distance(vec3(1.0, -1.0, 7.0)), 5.0);
+}
+
+#[test]
+fn vec_angle_between_test() {
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    assert_eq!(
+        vec3(1.0, 0.0, 0.0).angle_between(vec3(0.0, 2.0, 0.0)),
+        FRAC_PI_2
+    );
+    assert_eq!(vec2(-1.0, 0.0).angle_between(vec2(1.0, 0.0)), PI);
+
+    // The cosine of a vector with itself can round to slightly above 1.
+    let v = std140::vec::vec4(0.1, 0.2, 0.3, 0.7);
+    assert_eq!(v.angle_between(v), 0.0);
+    assert_eq!(vec3(0.3, 0.3, 0.3).angle_between(vec3(0.3, 0.3, 0.3)), 0.0);
+}
+
+#[test]
+fn vec_project_onto_test() {
+    assert_eq!(
+        vec3(2.0, 3.0, 4.0).project_onto(vec3(0.0, 5.0, 0.0)),
+        vec3(0.0, 3.0, 0.0)
+    );
+    assert_eq!(vec2(1.0, 1.0).project_onto(vec2(-2.0, 0.0)), vec2(1.0, 0.0));
+    assert_eq!(vec2(0.0, 1.0).project_onto(vec2(1.0, 0.0)), vec2(0.0, 0.0));
+    assert!(vec2(1.0, 1.0).project_onto(vec2::zero()).is_nan());
+}
+
+#[test]
+fn vec_angle_between_zero_test() {
+    assert!(vec3(1.0, 2.0, 3.0).angle_between(vec3::zero()).is_nan());
+    assert_eq!(vec2(1.0, 1.0).angle_between(vec2(3.0, 3.0)), 0.0);
+}
+
+#[test]
+fn vec_as_ref_test() {
+    fn floats(values: impl AsRef<[f32]>) -> Vec<f32> {
+        values.as_ref().to_vec()
+    }
+
+    let v = vec3(1.0, 2.0, 3.0);
+    let slice: &[f32] = v.as_ref();
+    let array: &[f32; 3] = v.as_ref();
+
+    assert_eq!(slice.len(), 3);
+    assert_eq!(array, &[1.0, 2.0, 3.0]);
+    assert_eq!(floats(vec2(1.0, 2.0)), [1.0, 2.0]);
+    assert_eq!(
+        floats(std140::vec::vec4(1.0, 2.0, 3.0, 4.0)),
+        [1.0, 2.0, 3.0, 4.0]
+    );
+}
+
+#[test]
+fn vec3_truncate_padding_test() {
+    assert_eq!(vec3(1.0, 2.0, 3.0).truncate_padding(), [1.0, 2.0, 3.0]);
+    assert_eq!(ivec3(-1, 0, 1).truncate_padding(), [-1, 0, 1]);
+    assert_eq!(std140::vec::uvec3(1, 2, 3).truncate_padding(), [1, 2, 3]);
+}
+
+#[test]
+fn vec3_padding_test() {
+    let mut v = vec3(1.0, 2.0, 3.0);
+    v.set_padding([0xde, 0xad, 0xbe, 0xef]);
+
+    assert_eq!(unsafe { v.padding_bytes() }, [0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(v.to_std140_bytes()[12..], [0; 4]);
+    assert_eq!(v.truncate_padding(), [1.0, 2.0, 3.0]);
+
+    let mut b = bvec3(boolean::True, boolean::False, boolean::True);
+    b.set_padding([0xff; 4]);
+    assert_eq!(unsafe { b.padding_bytes() }, [0xff; 4]);
+    assert_eq!(b, bvec3(boolean::True, boolean::False, boolean::True));
+
+    let mut u = std140::vec::uvec3(7, 8, 9);
+    u.set_padding([1, 2, 3, 4]);
+    assert_eq!(unsafe { u.padding_bytes() }, [1, 2, 3, 4]);
+    assert_eq!(u, std140::vec::uvec3(7, 8, 9));
+}
+
+#[test]
+#[should_panic(expected = "index 3 out of bounds for vec3 (len 3)")]
+fn vec_index_out_of_bounds_test() {
+    let v = vec3(1.0, 2.0, 3.0);
+    let _ = v[3];
+}
+
+#[test]
+#[should_panic(expected = "index 4 out of bounds for bvec4 (len 4)")]
+fn vec_index_mut_out_of_bounds_test() {
+    let mut v = std140::vec::bvec4(boolean::True, boolean::True, boolean::True, boolean::True);
+    v[4] = boolean::False;
+}
+
+#[test]
+fn vec_extend_truncate_test() {
+    use std140::vec::vec4;
+
+    assert_eq!(vec3(1.0, 2.0, 3.0).extend(4.0), vec4(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(vec2(1.0, 2.0).extend(3.0), vec3(1.0, 2.0, 3.0));
+    assert_eq!(vec4(1.0, 2.0, 3.0, 4.0).truncate(), vec3(1.0, 2.0, 3.0));
+    assert_eq!(vec3(1.0, 2.0, 3.0).truncate(), vec2(1.0, 2.0));
+    assert_eq!(ivec3(1, 2, 3).extend(-4), ivec4(1, 2, 3, -4));
+    assert_eq!(ivec3(1, 2, 3).extend(4).truncate(), ivec3(1, 2, 3));
+    assert_eq!(uvec2(1, 2).extend(3), std140::vec::uvec3(1, 2, 3));
+    assert_eq!(
+        std140::vec::uvec3(1, 2, 3).extend(0),
+        std140::vec::uvec4(1, 2, 3, 0)
+    );
+}
+
+#[test]
+fn vector_trait_test() {
+    use std140::vec::{uvec4, Vector};
+
+    fn from_repeated<V: Vector>(value: V::Scalar) -> V
+    where
+        V::Scalar: Copy,
+    {
+        V::from_scalar_slice(&vec![value; V::DIMENSIONS])
+    }
+
+    assert_eq!(<vec3 as Vector>::DIMENSIONS, 3);
+    assert_eq!(<uvec4 as Vector>::DIMENSIONS, 4);
+    assert_eq!(
+        vec3::from_scalar_slice(&[1.0, 2.0, 3.0]),
+        vec3(1.0, 2.0, 3.0)
+    );
+    assert_eq!(from_repeated::<ivec3>(-1), ivec3(-1, -1, -1));
+    assert_eq!(from_repeated::<uvec2>(7), uvec2(7, 7));
+}
+
+#[test]
+#[should_panic(expected = "slice length does not match the vec2 length")]
+fn vector_from_scalar_slice_length_mismatch_test() {
+    use std140::vec::Vector;
+
+    vec2::from_scalar_slice(&[1.0, 2.0, 3.0]);
+}
@@ -0,0 +1,25 @@
+use std140::{wgpu::Std140BufferExt, Std140Struct};
+
+#[std140::repr_std140]
+struct Lighting {
+    ambient: std140::vec::vec3,
+    exposure: std140::float,
+}
+
+#[test]
+fn create_std140_uniform_test() {
+    let (device, _queue) = wgpu::Device::noop(&wgpu::DeviceDescriptor::default());
+    let lighting = Lighting {
+        ambient: std140::vec::vec3(0.1, 0.1, 0.1),
+        exposure: std140::float(1.0),
+    };
+
+    let buffer =
+        device.create_std140_uniform(Some("lighting"), &lighting, wgpu::BufferUsages::COPY_DST);
+
+    assert_eq!(buffer.size(), lighting.to_std140_bytes().len() as u64);
+    assert_eq!(
+        buffer.usage(),
+        wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST
+    );
+}